@@ -9,7 +9,8 @@ use std::string::String;
 mod enumeration;
 pub mod parameter_substitution;
 use crate::selection::match_json_slice;
-use crate::selection::SelectionLens;
+pub use crate::selection::NormalForm;
+use crate::selection::Pattern;
 
 pub enum HighlightMatches {
     Never,
@@ -20,23 +21,26 @@ pub enum HighlightMatches {
 pub struct Config<'a> {
     pub matchers: Vec<&'a str>,
     pub params: Option<Vec<&'a str>>,
+    pub values_file: Option<&'a str>,
     pub input: Option<&'a str>,
     pub print_only_count: bool,
     pub print_line_number: bool,
     pub highlight_matches: HighlightMatches,
     pub ignore_case: bool,
+    pub normal_form: Option<NormalForm>,
+    pub ignore_accents: bool,
     pub is_quiet_mode: bool,
     pub invert_match: bool,
     pub match_root_only: bool,
     pub max_num: Option<usize>,
 }
 
-pub fn scan_input_for_matching_lines<'a>(
+type EnumeratedResult = (Option<usize>, Option<usize>, Result<String, String>);
+
+pub fn scan_input_for_matching_lines(
     config: &Config,
     on_line: &dyn Fn(String) -> Result<String, String>,
-    on_result: &dyn Fn(
-        (Option<usize>, Option<usize>, Result<String, String>),
-    ) -> (Option<usize>, Option<usize>),
+    on_result: &dyn Fn(EnumeratedResult) -> (Option<usize>, Option<usize>),
 ) -> Result<Option<usize>, Option<String>> {
     let stdin = io::stdin();
     let input = match config.input {
@@ -82,24 +86,18 @@ fn buffer_input_file(input: &str) -> Result<Box<dyn BufRead>, Option<String>> {
     }
 }
 
-pub fn in_configured_case(value: &str, config: &Config) -> String {
-    if config.ignore_case {
-        value.to_lowercase()
-    } else {
-        String::from(value)
-    }
-}
-
-pub fn match_line(
-    matchers: &Vec<Vec<Box<dyn SelectionLens>>>,
-    config: &Config,
-    input: String,
-) -> Result<String, String> {
-    match json::parse(&in_configured_case(&input, config)) {
+pub fn match_line(patterns: &[Pattern], config: &Config, input: String) -> Result<String, String> {
+    match json::parse(&input) {
         Ok(json_input) => {
-            let matches: Vec<&JsonValue> = matchers
+            let matches: Vec<&JsonValue> = patterns
                 .iter()
-                .map(|selector| match_json_slice(selector, &json_input, config.match_root_only))
+                .map(|pattern| {
+                    match_json_slice(
+                        &pattern.matchers,
+                        &json_input,
+                        config.match_root_only || pattern.is_anchored,
+                    )
+                })
                 .filter_map(Result::ok)
                 .collect();
 
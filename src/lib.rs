@@ -1,8 +1,10 @@
 #[macro_use]
 extern crate lazy_static;
+extern crate caseless;
 extern crate isatty;
 extern crate json_highlight_writer;
 extern crate regex;
+extern crate unicode_normalization;
 
 pub mod input;
 mod selection;
@@ -15,10 +17,17 @@ pub fn json_grep(config: input::Config) -> Result<(), Option<String>> {
         None => config.matchers.iter().map(|s| s.to_string()).collect(),
     };
 
+    let options = selection::PatternOptions {
+        folding: selection::StringFolding {
+            ignore_case: config.ignore_case,
+            normal_form: config.normal_form,
+            ignore_accents: config.ignore_accents,
+        },
+        values_file: config.values_file,
+    };
     let matched_filters: Result<Vec<_>, String> = lens_patterns
         .iter()
-        .map(|pattern| input::in_configured_case(pattern, &config))
-        .map(|pattern| selection::match_filters(&pattern))
+        .map(|pattern| selection::match_pattern(pattern, &options))
         .collect();
 
     let matched_filters = matched_filters?;
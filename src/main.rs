@@ -1,5 +1,5 @@
 extern crate jg;
-use jg::input::HighlightMatches;
+use jg::input::{HighlightMatches, NormalForm};
 
 use clap::{crate_version, App, Arg};
 use isatty::stdout_isatty;
@@ -54,6 +54,11 @@ fn main() {
                 .long("ignore-case")
                 .help("Perform case insensitive matching. By default, **jgrep** is case sensitive.")
         )
+        .arg(
+            Arg::with_name("ignore-accents")
+                .long("ignore-accents")
+                .help("Ignore accents and other diacritics when matching strings, so that \"Zurich\" matches \"Zürich\".")
+        )
         .arg(
             Arg::with_name("max-count")
                 .short("m")
@@ -67,6 +72,13 @@ fn main() {
                 .long("line-number")
                 .help("Each output line is preceded by its relative line number in the file, starting at line 1.")
         )
+        .arg(
+            Arg::with_name("normalize")
+                .long("normalize")
+                .takes_value(true)
+                .possible_values(&["nfc", "nfkc"])
+                .help("Compare strings after Unicode normalization, so that differently composed characters match.")
+        )
         .arg(
             Arg::with_name("params")
                 .multiple(true)
@@ -82,6 +94,12 @@ fn main() {
                 .visible_alias("silent")
                 .help("Quiet mode: suppress normal output.")
         )
+        .arg(
+            Arg::with_name("values-file")
+                .long("values-file")
+                .takes_value(true)
+                .help("File of values, one per line, which a bare \"@\" refers to in patterns such as '{\"id\" in @}'.")
+        )
         .arg(
             Arg::with_name("invert-match")
                 .short("v")
@@ -106,6 +124,7 @@ fn main() {
         params: matches
             .values_of("params")
             .map(|values| values.collect::<Vec<_>>()),
+        values_file: matches.value_of("values-file"),
         input: matches.value_of("file"),
         print_only_count: matches.is_present("count"),
         highlight_matches: match (matches.value_of("colour"), stdout_isatty()) {
@@ -115,6 +134,10 @@ fn main() {
         },
         print_line_number: matches.is_present("line-number"),
         ignore_case: matches.is_present("ignore-case"),
+        normal_form: matches
+            .value_of("normalize")
+            .and_then(NormalForm::from_name),
+        ignore_accents: matches.is_present("ignore-accents"),
         is_quiet_mode: matches.is_present("quiet"),
         match_root_only: matches.is_present("match-root"),
         invert_match: matches.is_present("invert-match"),
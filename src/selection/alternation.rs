@@ -0,0 +1,85 @@
+use json::JsonValue;
+
+use super::{select_all_from_slice, SelectionLens};
+
+pub struct Alternation {
+    pub branches: Vec<Vec<Box<dyn SelectionLens>>>,
+}
+
+impl SelectionLens for Alternation {
+    fn select<'a>(&self, input: Option<&'a JsonValue>) -> Option<&'a JsonValue> {
+        self.select_all(input).into_iter().next()
+    }
+
+    fn select_all<'a>(&self, input: Option<&'a JsonValue>) -> Vec<&'a JsonValue> {
+        match input {
+            Some(json_slice) => self
+                .branches
+                .iter()
+                .flat_map(|branch| select_all_from_slice(branch, json_slice))
+                .collect(),
+            None => vec![],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::selection::match_filters;
+    use json::object;
+
+    #[test]
+    fn should_select_from_whichever_branch_matches() {
+        let data = &object! {
+            "user" => object! { "phone" => "555-0100" }
+        };
+
+        match match_filters(".user(.email|.phone)") {
+            Ok(matchers) => {
+                assert_eq!(matchers.len(), 2);
+                assert_eq!(
+                    matchers[1].select(matchers[0].select(Some(data))),
+                    Some(&data["user"]["phone"])
+                );
+            }
+            _ => panic!("Invalid result"),
+        }
+    }
+
+    #[test]
+    fn should_select_every_branch_in_order() {
+        let data = &object! {
+            "email" => "jeff@example.com",
+            "phone" => "555-0100"
+        };
+
+        match match_filters(".phone|.email") {
+            Ok(matchers) => assert_eq!(
+                matchers[0].select_all(Some(data)),
+                vec![&data["phone"], &data["email"]]
+            ),
+            _ => panic!("Invalid result"),
+        }
+    }
+
+    #[test]
+    fn should_return_none_when_no_branch_matches() {
+        let data = &object! {
+            "level" => "info"
+        };
+
+        match match_filters(r#"{"level":"error"}|{"level":"fatal"}"#) {
+            Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), None),
+            _ => panic!("Invalid result"),
+        }
+    }
+
+    #[test]
+    fn should_return_none_when_json_isnt_present() {
+        let alternation = Alternation {
+            branches: vec![match_filters(".").unwrap()],
+        };
+        assert_eq!(alternation.select(None), None);
+    }
+}
@@ -1,51 +1,78 @@
 use json::JsonValue;
-use regex::Regex;
 
-use super::{value_matchers::*, SelectionLens, SelectionLensParser};
+use super::{value_matchers::*, SelectionLens};
 
-struct ArrayIndexMember {
-    index: usize,
+/// Negative indices count back from the end of the array, so `-1` is its last member.
+fn resolve_index(index: isize, length: usize) -> Option<usize> {
+    if index < 0 {
+        length.checked_sub(index.unsigned_abs())
+    } else {
+        Some(index as usize)
+    }
+}
+
+fn resolve_slice_bound(bound: isize, length: usize) -> usize {
+    resolve_index(bound, length).unwrap_or(0).min(length)
+}
+
+pub struct ArrayIndexMember {
+    pub index: isize,
 }
 
 impl SelectionLens for ArrayIndexMember {
     fn select<'a>(&self, input: Option<&'a JsonValue>) -> Option<&'a JsonValue> {
         match input {
-            Some(json) => match json {
-                JsonValue::Array(ref array) => array.get(self.index),
-                _ => None,
-            },
-            None => None,
+            Some(JsonValue::Array(ref array)) => {
+                resolve_index(self.index, array.len()).and_then(|index| array.get(index))
+            }
+            _ => None,
+        }
+    }
+}
+
+pub struct ArraySliceMember {
+    pub start: Option<isize>,
+    pub end: Option<isize>,
+}
+
+impl SelectionLens for ArraySliceMember {
+    fn select<'a>(&self, input: Option<&'a JsonValue>) -> Option<&'a JsonValue> {
+        self.select_all(input).into_iter().next()
+    }
+
+    fn select_all<'a>(&self, input: Option<&'a JsonValue>) -> Vec<&'a JsonValue> {
+        match input {
+            Some(JsonValue::Array(ref array)) => {
+                let start = self
+                    .start
+                    .map(|start| resolve_slice_bound(start, array.len()))
+                    .unwrap_or(0);
+                let end = self
+                    .end
+                    .map(|end| resolve_slice_bound(end, array.len()))
+                    .unwrap_or_else(|| array.len());
+                array
+                    .get(start..end.max(start))
+                    .map(|members| members.iter().collect())
+                    .unwrap_or_default()
+            }
+            _ => vec![],
         }
     }
 }
 
-struct ArrayValueMember {
-    value: JsonValueMemberMatcher,
+pub struct ArrayValueMember {
+    pub value: JsonValueMemberMatcher,
 }
 
 impl ArrayValueMember {
     pub fn member_in_array<'a>(
-        sequence: &'a Vec<JsonValue>,
+        sequence: &'a [JsonValue],
         json_value_matcher: &JsonValueMatcher,
     ) -> Option<&'a JsonValue> {
         sequence
             .iter()
-            .find(|member| match (member, json_value_matcher) {
-                (JsonValue::Short(string_prop), JsonValueMatcher::String(string_value)) => {
-                    string_value.eq(string_prop)
-                }
-                (JsonValue::String(string_prop), JsonValueMatcher::String(string_value)) => {
-                    string_value.eq(string_prop)
-                }
-                (JsonValue::Boolean(bool_prop), JsonValueMatcher::Boolean(bool_value)) => {
-                    bool_prop.eq(bool_value)
-                }
-                (JsonValue::Number(num_prop), JsonValueMatcher::Number(num_value)) => {
-                    num_prop.eq(num_value)
-                }
-                (JsonValue::Null, JsonValueMatcher::Null) => true,
-                _ => false,
-            })
+            .find(|member| value_matches_exact(member, json_value_matcher))
     }
 }
 
@@ -63,119 +90,55 @@ impl SelectionLens for ArrayValueMember {
                 JsonValueMemberMatcher::ContainsExact(json_value_matcher) => {
                     ArrayValueMember::member_in_array(array, json_value_matcher)
                 }
-                JsonValueMemberMatcher::Prefixed(json_value_matcher) => {
-                    array
-                        .iter()
-                        .find(|member| match (member, json_value_matcher) {
-                            (
-                                JsonValue::Short(string_prop),
-                                JsonValueMatcher::String(string_value),
-                            ) => string_prop.starts_with(string_value),
-                            (
-                                JsonValue::String(string_prop),
-                                JsonValueMatcher::String(string_value),
-                            ) => string_prop.starts_with(string_value),
-                            _ => false,
-                        })
-                }
-                JsonValueMemberMatcher::Suffixed(json_value_matcher) => {
-                    array
-                        .iter()
-                        .find(|member| match (member, json_value_matcher) {
-                            (
-                                JsonValue::Short(string_prop),
-                                JsonValueMatcher::String(string_value),
-                            ) => string_prop.ends_with(string_value),
-                            (
-                                JsonValue::String(string_prop),
-                                JsonValueMatcher::String(string_value),
-                            ) => string_prop.ends_with(string_value),
-                            _ => false,
-                        })
-                }
-                JsonValueMemberMatcher::Contains(json_value_matcher) => {
-                    array
-                        .iter()
-                        .find(|member| match (member, json_value_matcher) {
-                            (
-                                JsonValue::Short(string_prop),
-                                JsonValueMatcher::String(string_value),
-                            ) => string_prop.contains(string_value),
-                            (
-                                JsonValue::String(string_prop),
-                                JsonValueMatcher::String(string_value),
-                            ) => string_prop.contains(string_value),
-                            _ => false,
-                        })
-                }
+                json_value_comparison => array
+                    .iter()
+                    .find(|member| value_satisfies(member, json_value_comparison)),
             },
             _ => None,
         }
     }
 }
 
-enum ArrayMember {
-    Index(usize),
-    Value(JsonValueMemberMatcher),
+/// Matches an array whose members satisfy each of the matchers in order, either as a
+/// subsequence of the array or, for a whole array, one member per matcher.
+pub struct ArrayMembers {
+    pub members: Vec<JsonValueMemberMatcher>,
+    pub is_whole_array: bool,
 }
 
-pub struct ArrayMemberParser;
-impl ArrayMemberParser {
-    fn match_array_member(pattern: &str) -> Option<(ArrayMember, Option<&str>)> {
-        lazy_static! {
-        static ref RE_INDEX: Regex =
-            Regex::new(r#"^\[(?P<index>([[:digit:]])+)\](?P<remainder>.+)?$"#).unwrap();
-        static ref RE_MEMBER: Regex = Regex::new(
-            concat!(r#"^\["#,r#"(?P<matchingStrategy>(~=|=|\$=|\^=|\*=)+)"#,r#"("(?P<stringValue>([^"])+)"|(?P<numberValue>([[:digit:]]+)+)|(?P<literalValue>([[:word:]])+))\](?P<remainder>.+)?$"#)
-        )
-        .unwrap();
+impl ArrayMembers {
+    fn contains_in_order(&self, array: &[JsonValue]) -> bool {
+        let mut remaining_members = self.members.iter().peekable();
+        for member in array {
+            if let Some(member_matcher) = remaining_members.peek() {
+                if value_satisfies(member, member_matcher) {
+                    remaining_members.next();
+                }
+            }
         }
+        remaining_members.peek().is_none()
+    }
 
-        match RE_INDEX.captures(pattern) {
-            Some(captured_index) => captured_index
-                .name("index")
-                .map(|index| index.as_str())
-                .map(|index| usize::from_str_radix(index, 32))
-                .filter(|index| index.is_ok())
-                .map(|index| {
-                    (
-                        ArrayMember::Index(index.unwrap()),
-                        captured_index
-                            .name("remainder")
-                            .map(|remainder| remainder.as_str()),
-                    )
-                }),
-            None => match RE_MEMBER.captures(pattern) {
-                Some(cap) => match identify_value_matcher(&cap) {
-                    Ok(Some(json_matcher)) => Some((
-                        ArrayMember::Value(json_matcher),
-                        cap.name("remainder").map(|remainder| remainder.as_str()),
-                    )),
-                    Ok(None) => None,
-                    Err(_) => None,
-                },
-                None => None,
-            },
-        }
+    fn matches_whole_array(&self, array: &[JsonValue]) -> bool {
+        array.len() == self.members.len()
+            && array
+                .iter()
+                .zip(&self.members)
+                .all(|(member, member_matcher)| value_satisfies(member, member_matcher))
     }
 }
-impl SelectionLensParser for ArrayMemberParser {
-    fn try_parse<'a>(
-        &self,
-        lens_pattern: Option<&'a str>,
-    ) -> Result<(Box<dyn SelectionLens>, Option<&'a str>), Option<&'a str>> {
-        match lens_pattern {
-            Some(pattern) => match ArrayMemberParser::match_array_member(pattern) {
-                Some((array_member, remainder)) => Ok((
-                    match array_member {
-                        ArrayMember::Index(index) => Box::new(ArrayIndexMember { index }),
-                        ArrayMember::Value(value) => Box::new(ArrayValueMember { value }),
-                    },
-                    remainder,
-                )),
-                None => Err(lens_pattern),
-            },
-            None => Err(lens_pattern),
+
+impl SelectionLens for ArrayMembers {
+    fn select<'a>(&self, input: Option<&'a JsonValue>) -> Option<&'a JsonValue> {
+        match input {
+            Some(JsonValue::Array(ref array)) => input.filter(|_| {
+                if self.is_whole_array {
+                    self.matches_whole_array(array)
+                } else {
+                    self.contains_in_order(array)
+                }
+            }),
+            _ => None,
         }
     }
 }
@@ -183,13 +146,13 @@ impl SelectionLensParser for ArrayMemberParser {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::selection::match_filters;
     use json::array;
     use json::object;
 
     #[test]
     fn should_match_array_index() {
-        let array_member_parser = ArrayMemberParser {};
-        let res = array_member_parser.try_parse(Some("[0]"));
+        let res = match_filters("[0]");
         assert!(res.is_ok());
 
         let data = &array![object! {
@@ -198,19 +161,28 @@ mod tests {
         }];
 
         match res {
-            Ok((matcher, _)) => assert_eq!(matcher.select(Some(data)), Some(&data[0])),
+            Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), Some(&data[0])),
             _ => panic!("Invalid result"),
         }
     }
 
     #[test]
-    fn should_return_remainder_when_it_matches_index() {
-        let array_member_parser = ArrayMemberParser {};
-        let res = array_member_parser.try_parse(Some("[0].title"));
+    fn should_match_prop_chained_after_index() {
+        let res = match_filters("[0].title");
         assert!(res.is_ok());
 
+        let data = &array![object! {
+            "title"    => "Mr"
+        }];
+
         match res {
-            Ok((_, umatched)) => assert_eq!(umatched, Some(".title")),
+            Ok(matchers) => {
+                assert_eq!(matchers.len(), 2);
+                assert_eq!(
+                    matchers[1].select(matchers[0].select(Some(data))),
+                    Some(&data[0]["title"])
+                );
+            }
             _ => panic!("Invalid result"),
         }
     }
@@ -232,6 +204,110 @@ mod tests {
         assert_eq!(array_index.select(None), None);
     }
 
+    #[test]
+    fn should_count_negative_index_from_the_end() {
+        let data = &array!["first", "second", "last"];
+
+        assert_eq!(
+            ArrayIndexMember { index: -1 }.select(Some(data)),
+            Some(&data[2])
+        );
+        assert_eq!(
+            ArrayIndexMember { index: -3 }.select(Some(data)),
+            Some(&data[0])
+        );
+        assert_eq!(ArrayIndexMember { index: -4 }.select(Some(data)), None);
+    }
+
+    #[test]
+    fn should_select_every_member_within_slice() {
+        let data = &array![0, 1, 2, 3, 4, 5];
+
+        match match_filters("[1:3]") {
+            Ok(matchers) => {
+                assert_eq!(matchers[0].select_all(Some(data)), vec![&data[1], &data[2]])
+            }
+            _ => panic!("Invalid result"),
+        }
+
+        match match_filters("[-2:]") {
+            Ok(matchers) => {
+                assert_eq!(matchers[0].select_all(Some(data)), vec![&data[4], &data[5]])
+            }
+            _ => panic!("Invalid result"),
+        }
+
+        match match_filters("[:-5]") {
+            Ok(matchers) => assert_eq!(matchers[0].select_all(Some(data)), vec![&data[0]]),
+            _ => panic!("Invalid result"),
+        }
+    }
+
+    #[test]
+    fn should_clamp_slice_to_the_bounds_of_the_array() {
+        let data = &array![0, 1, 2];
+
+        let slice = ArraySliceMember {
+            start: Some(-10),
+            end: Some(10),
+        };
+        assert_eq!(slice.select_all(Some(data)).len(), 3);
+
+        let slice = ArraySliceMember {
+            start: Some(2),
+            end: Some(1),
+        };
+        assert_eq!(slice.select(Some(data)), None);
+    }
+
+    #[test]
+    fn should_select_array_containing_members_in_order() {
+        let data = &array!["login", "view", "logout", "login"];
+
+        for pattern in &[
+            r#"[="login", ="logout"]"#,
+            r#"[="view",="login"]"#,
+            r#"[^="log", $="out"]"#,
+        ] {
+            match match_filters(pattern) {
+                Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), Some(data)),
+                _ => panic!("Invalid result"),
+            }
+        }
+
+        for pattern in &[
+            r#"[="logout", ="view"]"#,
+            r#"[="login", ="login", ="login"]"#,
+        ] {
+            match match_filters(pattern) {
+                Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), None),
+                _ => panic!("Invalid result"),
+            }
+        }
+    }
+
+    #[test]
+    fn should_select_array_with_exactly_the_listed_members() {
+        let data = &array!["a", "b"];
+
+        match match_filters(r#"[=="a","b"]"#) {
+            Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), Some(data)),
+            _ => panic!("Invalid result"),
+        }
+
+        for pattern in &[r#"[=="a"]"#, r#"[=="b","a"]"#, r#"[=="a","b","c"]"#, "[==]"] {
+            match match_filters(pattern) {
+                Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), None),
+                _ => panic!("Invalid result"),
+            }
+        }
+
+        match match_filters("[==]") {
+            Ok(matchers) => assert_eq!(matchers[0].select(Some(&array![])), Some(&array![])),
+            _ => panic!("Invalid result"),
+        }
+    }
+
     #[test]
     fn should_return_node_when_exact_string_value_is_only_value_in_array() {
         let data = &array!["Jane Doe"];
@@ -291,23 +367,128 @@ mod tests {
         let data = &array![0, -10, 10, 123456789];
 
         let array_member = ArrayValueMember {
-            value: JsonValueMemberMatcher::ContainsExact(JsonValueMatcher::Number(0)),
+            value: JsonValueMemberMatcher::ContainsExact(JsonValueMatcher::Number(0.into())),
         };
         assert_eq!(array_member.select(Some(data)), Some(&data[0]));
 
         let array_member = ArrayValueMember {
-            value: JsonValueMemberMatcher::ContainsExact(JsonValueMatcher::Number(-10)),
+            value: JsonValueMemberMatcher::ContainsExact(JsonValueMatcher::Number((-10).into())),
         };
         assert_eq!(array_member.select(Some(data)), Some(&data[1]));
 
         let array_member = ArrayValueMember {
-            value: JsonValueMemberMatcher::ContainsExact(JsonValueMatcher::Number(10)),
+            value: JsonValueMemberMatcher::ContainsExact(JsonValueMatcher::Number(10.into())),
+        };
+        assert_eq!(array_member.select(Some(data)), Some(&data[2]));
+
+        let array_member = ArrayValueMember {
+            value: JsonValueMemberMatcher::ContainsExact(JsonValueMatcher::Number(
+                123456789.into(),
+            )),
+        };
+        assert_eq!(array_member.select(Some(data)), Some(&data[3]));
+    }
+
+    #[test]
+    fn should_return_first_node_which_satisfies_numeric_comparison() {
+        let data = &array!["10", 2, 10, 25];
+
+        let array_member = ArrayValueMember {
+            value: JsonValueMemberMatcher::GreaterThan(JsonValueMatcher::Number(5.into())),
         };
         assert_eq!(array_member.select(Some(data)), Some(&data[2]));
 
         let array_member = ArrayValueMember {
-            value: JsonValueMemberMatcher::ContainsExact(JsonValueMatcher::Number(123456789)),
+            value: JsonValueMemberMatcher::GreaterThanOrEqual(JsonValueMatcher::Number(25.into())),
         };
         assert_eq!(array_member.select(Some(data)), Some(&data[3]));
+
+        let array_member = ArrayValueMember {
+            value: JsonValueMemberMatcher::LessThanOrEqual(JsonValueMatcher::Number(2.into())),
+        };
+        assert_eq!(array_member.select(Some(data)), Some(&data[1]));
+
+        let array_member = ArrayValueMember {
+            value: JsonValueMemberMatcher::LessThan(JsonValueMatcher::Number(2.into())),
+        };
+        assert_eq!(array_member.select(Some(data)), None);
+    }
+
+    #[test]
+    fn should_return_first_node_which_is_not_equal_to_value() {
+        let data = &array![10, 10, "10"];
+
+        let array_member = ArrayValueMember {
+            value: JsonValueMemberMatcher::NotEqual(JsonValueMatcher::Number(10.into())),
+        };
+        assert_eq!(array_member.select(Some(data)), Some(&data[2]));
+    }
+
+    #[test]
+    fn should_parse_comparison_matching_strategies() {
+        for pattern in &["[>5]", "[>=10]", "[<11]", "[<=10]", "[!=5]"] {
+            let res = match_filters(pattern);
+            assert!(res.is_ok());
+
+            match res {
+                Ok(matchers) => assert_eq!(matchers[0].select(Some(&array![10])), Some(&10.into())),
+                _ => panic!("Invalid result"),
+            }
+        }
+    }
+
+    #[test]
+    fn should_return_first_node_within_range() {
+        let data = &array!["5", 0, 5, 10];
+
+        match match_filters("[=1..10]") {
+            Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), Some(&data[2])),
+            _ => panic!("Invalid result"),
+        }
+
+        match match_filters("[=6..=10]") {
+            Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), Some(&data[3])),
+            _ => panic!("Invalid result"),
+        }
+
+        match match_filters("[=6..10]") {
+            Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), Some(&data[3])),
+            _ => panic!("Invalid result"),
+        }
+
+        match match_filters("[=6..<10]") {
+            Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), None),
+            _ => panic!("Invalid result"),
+        }
+    }
+
+    #[test]
+    fn should_return_first_node_matching_regex() {
+        let data = &array![2, "latest", "v1.2.0", "v2.0.0"];
+
+        match match_filters(r#"[~/^v\d+\./]"#) {
+            Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), Some(&data[2])),
+            _ => panic!("Invalid result"),
+        }
+
+        match match_filters(r#"[~/^v3/]"#) {
+            Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), None),
+            _ => panic!("Invalid result"),
+        }
+    }
+
+    #[test]
+    fn should_parse_string_values_with_escapes() {
+        let data = &array!["", "say \"hi\""];
+
+        match match_filters(r#"[~=""]"#) {
+            Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), Some(&data[0])),
+            _ => panic!("Invalid result"),
+        }
+
+        match match_filters(r#"[~="say \"hi\""]"#) {
+            Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), Some(&data[1])),
+            _ => panic!("Invalid result"),
+        }
     }
 }
@@ -0,0 +1,80 @@
+use json::JsonValue;
+
+use super::{select_from_slice, SelectionLens};
+
+/// Selects its input, rather than moving into it, so that the lenses which follow a
+/// conjunction are applied to the same value every one of its branches matched.
+pub struct Conjunction {
+    pub branches: Vec<Vec<Box<dyn SelectionLens>>>,
+}
+
+impl SelectionLens for Conjunction {
+    fn select<'a>(&self, input: Option<&'a JsonValue>) -> Option<&'a JsonValue> {
+        input.filter(|json_slice| {
+            self.branches
+                .iter()
+                .all(|branch| select_from_slice(branch, json_slice).is_some())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::selection::match_filters;
+    use json::array;
+    use json::object;
+
+    #[test]
+    fn should_select_object_when_every_prop_matches() {
+        let data = &object! {
+            "level" => "error",
+            "service" => "auth"
+        };
+
+        match match_filters(r#"{"level":"error","service":"auth"}"#) {
+            Ok(matchers) => {
+                assert_eq!(matchers.len(), 1);
+                assert_eq!(matchers[0].select(Some(data)), Some(data));
+            }
+            _ => panic!("Invalid result"),
+        }
+
+        match match_filters(r#"{"level":"error","service":"billing"}"#) {
+            Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), None),
+            _ => panic!("Invalid result"),
+        }
+    }
+
+    #[test]
+    fn should_select_object_when_every_chain_in_group_matches() {
+        let data = &object! {
+            "req" => object! {
+                "method" => "GET",
+                "path" => "/",
+                "headers" => array![object! { "host" => "example.com" }]
+            }
+        };
+
+        match match_filters(".req(.method, .headers[.host])") {
+            Ok(matchers) => assert_eq!(
+                matchers[1].select(matchers[0].select(Some(data))),
+                Some(&data["req"])
+            ),
+            _ => panic!("Invalid result"),
+        }
+
+        match match_filters(".req(.method,.body)") {
+            Ok(matchers) => assert_eq!(matchers[1].select(matchers[0].select(Some(data))), None),
+            _ => panic!("Invalid result"),
+        }
+    }
+
+    #[test]
+    fn should_return_none_when_json_isnt_present() {
+        let conjunction = Conjunction {
+            branches: vec![match_filters(".").unwrap()],
+        };
+        assert_eq!(conjunction.select(None), None);
+    }
+}
@@ -0,0 +1,91 @@
+use json::JsonValue;
+
+use super::SelectionLens;
+
+pub struct Descendant {
+    pub matcher: Box<dyn SelectionLens>,
+}
+
+fn self_and_descendants<'a>(json_slice: &'a JsonValue, slices: &mut Vec<&'a JsonValue>) {
+    slices.push(json_slice);
+    match json_slice {
+        JsonValue::Object(ref object) => object
+            .iter()
+            .for_each(|(_, value)| self_and_descendants(value, slices)),
+        JsonValue::Array(ref sequence) => sequence
+            .iter()
+            .for_each(|member| self_and_descendants(member, slices)),
+        _ => {}
+    }
+}
+
+impl SelectionLens for Descendant {
+    fn select<'a>(&self, input: Option<&'a JsonValue>) -> Option<&'a JsonValue> {
+        self.select_all(input).into_iter().next()
+    }
+
+    fn select_all<'a>(&self, input: Option<&'a JsonValue>) -> Vec<&'a JsonValue> {
+        let mut slices = vec![];
+        if let Some(json_slice) = input {
+            self_and_descendants(json_slice, &mut slices);
+        }
+
+        slices
+            .into_iter()
+            .flat_map(|json_slice| self.matcher.select_all(Some(json_slice)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::selection::match_filters;
+    use json::array;
+    use json::object;
+
+    #[test]
+    fn should_select_step_at_any_depth() {
+        let data = &object! {
+            "request" => object! {
+                "token" => "abc",
+                "headers" => array![object! { "token" => "def" }]
+            }
+        };
+
+        match match_filters(".request..token") {
+            Ok(matchers) => {
+                let request = matchers[0].select(Some(data));
+                assert_eq!(
+                    matchers[1].select_all(request),
+                    vec![
+                        &data["request"]["token"],
+                        &data["request"]["headers"][0]["token"]
+                    ]
+                );
+            }
+            _ => panic!("Invalid result"),
+        }
+    }
+
+    #[test]
+    fn should_only_select_below_the_preceding_step() {
+        let data = &object! {
+            "token" => "abc",
+            "request" => object! { "method" => "GET" }
+        };
+
+        match match_filters(".request..token") {
+            Ok(matchers) => assert_eq!(matchers[1].select(matchers[0].select(Some(data))), None),
+            _ => panic!("Invalid result"),
+        }
+    }
+
+    #[test]
+    fn should_return_none_when_json_isnt_present() {
+        let descendant = Descendant {
+            matcher: match_filters(".token").unwrap().remove(0),
+        };
+        assert_eq!(descendant.select(None), None);
+    }
+}
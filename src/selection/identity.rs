@@ -1,39 +1,23 @@
 use json::JsonValue;
 
-use super::{SelectionLens, SelectionLensParser};
+use super::SelectionLens;
 
-struct Identity;
+pub struct Identity;
 impl SelectionLens for Identity {
     fn select<'a>(&self, input: Option<&'a JsonValue>) -> Option<&'a JsonValue> {
         input
     }
 }
 
-pub struct IdentityParser;
-impl SelectionLensParser for IdentityParser {
-    fn try_parse<'a>(
-        &self,
-        lens_pattern: Option<&'a str>,
-    ) -> Result<(Box<dyn SelectionLens>, Option<&'a str>), Option<&'a str>> {
-        match lens_pattern {
-            Some(pattern) => match pattern {
-                "." => Ok((Box::new(Identity {}), None)),
-                _ => Err(lens_pattern),
-            },
-            None => Err(lens_pattern),
-        }
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::selection::match_filters;
     use json::object;
 
     #[test]
     fn should_match_dot() {
-        let identity_parser = IdentityParser {};
-        let res = identity_parser.try_parse(Some("."));
+        let res = match_filters(".");
         assert!(res.is_ok());
 
         let data = &object! {
@@ -42,9 +26,9 @@ mod tests {
         };
 
         match res {
-            Ok((lens, unmatched)) => {
-                assert_eq!(lens.select(Some(data)), Some(data));
-                assert_eq!(unmatched, None);
+            Ok(lenses) => {
+                assert_eq!(lenses[0].select(Some(data)), Some(data));
+                assert_eq!(lenses.len(), 1);
             }
             _ => panic!("Invalid result"),
         }
@@ -52,11 +36,12 @@ mod tests {
 
     #[test]
     fn shouldnt_match_anything_else() {
-        let identity_parser = IdentityParser {};
-        let res = identity_parser.try_parse(Some(".prop"));
-        assert!(res.is_err());
-        match res {
-            Err(Some(selector)) => assert_eq!(selector, ".prop"),
+        let data = &object! {
+            "name"    => "John Doe"
+        };
+
+        match match_filters(".prop") {
+            Ok(lenses) => assert_eq!(lenses[0].select(Some(data)), None),
             _ => panic!("Invalid result"),
         }
     }
@@ -2,29 +2,68 @@ use json::JsonValue;
 
 pub trait SelectionLens {
     fn select<'a>(&self, input: Option<&'a JsonValue>) -> Option<&'a JsonValue>;
-}
 
-pub trait SelectionLensParser {
-    fn try_parse<'a>(
-        &self,
-        lens_pattern: Option<&'a str>,
-    ) -> Result<(Box<dyn SelectionLens>, Option<&'a str>), Option<&'a str>>;
+    /// Every slice of the input this lens could select, so that the lenses which follow
+    /// it in a pattern can be tried against each of them in turn.
+    fn select_all<'a>(&self, input: Option<&'a JsonValue>) -> Vec<&'a JsonValue> {
+        self.select(input).into_iter().collect()
+    }
 }
 
+mod alternation;
 mod array_member;
+mod conjunction;
+mod descendant;
 mod identity;
+mod negation;
+mod normalization;
+mod parser;
 mod prop;
 mod sequence;
+mod size;
+mod tokenizer;
 mod value_matchers;
+mod value_type;
+mod values_file;
+
+pub use normalization::NormalForm;
+pub use parser::PatternOptions;
+use parser::Selector;
+pub use value_matchers::StringFolding;
+
+fn select_from_slice<'a>(
+    matchers: &[Box<dyn SelectionLens>],
+    json_slice: &'a JsonValue,
+) -> Option<&'a JsonValue> {
+    match matchers.split_first() {
+        Some((matcher, remaining_matchers)) => matcher
+            .select_all(Some(json_slice))
+            .into_iter()
+            .find_map(|selected_slice| select_from_slice(remaining_matchers, selected_slice)),
+        None => Some(json_slice),
+    }
+}
+
+fn select_all_from_slice<'a>(
+    matchers: &[Box<dyn SelectionLens>],
+    json_slice: &'a JsonValue,
+) -> Vec<&'a JsonValue> {
+    match matchers.split_first() {
+        Some((matcher, remaining_matchers)) => matcher
+            .select_all(Some(json_slice))
+            .into_iter()
+            .flat_map(|selected_slice| select_all_from_slice(remaining_matchers, selected_slice))
+            .collect(),
+        None => vec![json_slice],
+    }
+}
 
 pub fn match_json_slice<'a>(
     matchers: &Vec<Box<dyn SelectionLens>>,
     json_input: &'a JsonValue,
     match_root_only: bool,
 ) -> Result<&'a JsonValue, ()> {
-    match matchers.iter().try_fold(json_input, |json_slice, matcher| {
-        matcher.select(Some(json_slice))
-    }) {
+    match select_from_slice(matchers, json_input) {
         Some(matching_slice) => Ok(matching_slice),
         None => match (match_root_only, json_input) {
             (false, JsonValue::Object(ref object)) => match object
@@ -48,45 +87,68 @@ pub fn match_json_slice<'a>(
     }
 }
 
-pub fn match_filter(filter: &str) -> Result<(Box<dyn SelectionLens>, Option<&str>), &str> {
-    lazy_static! {
-        static ref IDENTITY_PARSER: identity::IdentityParser = identity::IdentityParser {};
-        static ref PROP_PARSER: prop::PropParser = prop::PropParser {};
-        static ref ARRAY_MEMBER_PARSER: array_member::ArrayMemberParser =
-            array_member::ArrayMemberParser {};
-        static ref SEQUENCE_PARSER: sequence::SequenceParser = sequence::SequenceParser {};
+fn lens_for(selector: Selector) -> Box<dyn SelectionLens> {
+    match selector {
+        Selector::Identity => Box::new(identity::Identity {}),
+        Selector::Prop { name, value } => Box::new(prop::Prop { name, value }),
+        Selector::ArrayIndex(index) => Box::new(array_member::ArrayIndexMember { index }),
+        Selector::ArraySlice { start, end } => {
+            Box::new(array_member::ArraySliceMember { start, end })
+        }
+        Selector::ArrayValue(value) => Box::new(array_member::ArrayValueMember { value }),
+        Selector::ArrayMembers {
+            members,
+            is_whole_array,
+        } => Box::new(array_member::ArrayMembers {
+            members,
+            is_whole_array,
+        }),
+        Selector::Sequence(selectors) => Box::new(sequence::Sequence {
+            matchers: lenses_for(selectors),
+        }),
+        Selector::QuantifiedSequence {
+            quantifier,
+            selectors,
+        } => Box::new(sequence::QuantifiedSequence {
+            quantifier,
+            matchers: lenses_for(selectors),
+        }),
+        Selector::Alternation(branches) => Box::new(alternation::Alternation {
+            branches: branches.into_iter().map(lenses_for).collect(),
+        }),
+        Selector::Conjunction(branches) => Box::new(conjunction::Conjunction {
+            branches: branches.into_iter().map(lenses_for).collect(),
+        }),
+        Selector::Negation(selectors) => Box::new(negation::Negation {
+            matchers: lenses_for(selectors),
+        }),
+        Selector::Descendant(selector) => Box::new(descendant::Descendant {
+            matcher: lens_for(*selector),
+        }),
+        Selector::Size(count) => Box::new(size::Size { count }),
+        Selector::ValueType(json_type) => Box::new(value_type::ValueType { json_type }),
     }
+}
 
-    IDENTITY_PARSER
-        .try_parse(Some(filter))
-        .or_else(|unmatched_filter| PROP_PARSER.try_parse(unmatched_filter))
-        .or_else(|unmatched_filter| ARRAY_MEMBER_PARSER.try_parse(unmatched_filter))
-        .or_else(|unmatched_filter| SEQUENCE_PARSER.try_parse(unmatched_filter))
-        .map_err(|_| filter)
+fn lenses_for(selectors: Vec<Selector>) -> Vec<Box<dyn SelectionLens>> {
+    selectors.into_iter().map(lens_for).collect()
 }
 
-pub fn try_to_match_filters(filter: &str) -> Result<Vec<Box<dyn SelectionLens>>, &str> {
-    let mut matchers: Vec<Box<dyn SelectionLens>> = vec![];
-    let mut unmatched_filter: Result<Option<&str>, &str> = Ok(Some(filter));
-    while let Ok(Some(filter)) = unmatched_filter {
-        match match_filter(filter) {
-            Ok((matcher, remainder)) => {
-                matchers.push(matcher);
-                unmatched_filter = Ok(remainder);
-            }
-            Err(remaining_filter) => {
-                unmatched_filter = Err(remaining_filter);
-            }
-        };
-    }
-    match unmatched_filter {
-        Ok(None) => Ok(matchers),
-        Ok(Some(remaining_filter)) => Err(remaining_filter),
-        Err(remaining_filter) => Err(remaining_filter),
-    }
+pub struct Pattern {
+    pub matchers: Vec<Box<dyn SelectionLens>>,
+    pub is_anchored: bool,
+}
+
+pub fn match_pattern(filter: &str, options: &PatternOptions) -> Result<Pattern, String> {
+    parser::parse(filter, options)
+        .map(|parsed| Pattern {
+            matchers: lenses_for(parsed.selectors),
+            is_anchored: parsed.is_anchored,
+        })
+        .map_err(|parse_error| parse_error.describe(filter))
 }
 
+#[cfg(test)]
 pub fn match_filters(filter: &str) -> Result<Vec<Box<dyn SelectionLens>>, String> {
-    try_to_match_filters(filter)
-        .map_err(|unmatched_filter| format!("Invalid filter: {:?}", unmatched_filter))
+    match_pattern(filter, &PatternOptions::default()).map(|pattern| pattern.matchers)
 }
@@ -0,0 +1,81 @@
+use json::JsonValue;
+
+use super::{select_from_slice, SelectionLens};
+
+pub struct Negation {
+    pub matchers: Vec<Box<dyn SelectionLens>>,
+}
+
+impl SelectionLens for Negation {
+    fn select<'a>(&self, input: Option<&'a JsonValue>) -> Option<&'a JsonValue> {
+        // Only objects and arrays can lack a path, otherwise an unanchored pattern would
+        // match on any scalar found while searching inside the input.
+        input
+            .filter(|json_slice| matches!(json_slice, JsonValue::Object(_) | JsonValue::Array(_)))
+            .filter(|json_slice| select_from_slice(&self.matchers, json_slice).is_none())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::selection::match_filters;
+    use json::array;
+    use json::object;
+
+    #[test]
+    fn should_select_input_when_negated_pattern_doesnt_match() {
+        let data = &object! {
+            "user" => object! { "name" => "jeff goldblum" }
+        };
+
+        match match_filters(".user:not(.email)") {
+            Ok(matchers) => assert_eq!(
+                matchers[1].select(matchers[0].select(Some(data))),
+                Some(&data["user"])
+            ),
+            _ => panic!("Invalid result"),
+        }
+
+        match match_filters(".user:not(.name)") {
+            Ok(matchers) => assert_eq!(matchers[1].select(matchers[0].select(Some(data))), None),
+            _ => panic!("Invalid result"),
+        }
+    }
+
+    #[test]
+    fn should_negate_the_rest_of_the_chain_after_a_bang() {
+        let data = &object! {
+            "items" => array![1],
+            "shipping" => object! { "city" => "Paris" }
+        };
+
+        match match_filters("!.shipping.address") {
+            Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), Some(data)),
+            _ => panic!("Invalid result"),
+        }
+
+        match match_filters("!.shipping.city") {
+            Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), None),
+            _ => panic!("Invalid result"),
+        }
+    }
+
+    #[test]
+    fn shouldnt_select_scalars() {
+        let negation = Negation {
+            matchers: match_filters(".name").unwrap(),
+        };
+        assert_eq!(negation.select(Some(&JsonValue::from(1))), None);
+        assert_eq!(negation.select(Some(&JsonValue::from("name"))), None);
+        assert_eq!(negation.select(Some(&array![1])), Some(&array![1]));
+    }
+
+    #[test]
+    fn should_return_none_when_json_isnt_present() {
+        let negation = Negation {
+            matchers: match_filters(".name").unwrap(),
+        };
+        assert_eq!(negation.select(None), None);
+    }
+}
@@ -0,0 +1,83 @@
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NormalForm {
+    /// NFC, under which precomposed and decomposed accents are equal.
+    Canonical,
+    /// NFKC, which also treats ligatures, full width forms, super and subscripts and
+    /// other compatibility characters as their plain counterparts.
+    Compatibility,
+}
+
+impl NormalForm {
+    pub fn from_name(name: &str) -> Option<NormalForm> {
+        match name {
+            "nfc" => Some(NormalForm::Canonical),
+            "nfkc" => Some(NormalForm::Compatibility),
+            _ => None,
+        }
+    }
+}
+
+/// Letters whose diacritic is part of the glyph rather than a combining mark, so they
+/// have no decomposition to strip it from.
+#[rustfmt::skip]
+const UNDECOMPOSABLE_DIACRITICS: &[(char, char)] = &[
+    ('Đ', 'D'), ('đ', 'd'), ('Ħ', 'H'), ('ħ', 'h'), ('ı', 'i'), ('Ł', 'L'), ('ł', 'l'),
+    ('Ŧ', 'T'), ('ŧ', 't'), ('Ø', 'O'), ('ø', 'o'), ('ƀ', 'b'), ('Ɨ', 'I'), ('ɨ', 'i'),
+];
+
+pub fn normalize(value: &str, form: NormalForm) -> String {
+    match form {
+        NormalForm::Canonical => value.nfc().collect(),
+        NormalForm::Compatibility => value.nfkc().collect(),
+    }
+}
+
+pub fn strip_diacritics(value: &str) -> String {
+    value
+        .nfd()
+        .filter(|&character| !is_combining_mark(character))
+        .map(|character| {
+            UNDECOMPOSABLE_DIACRITICS
+                .iter()
+                .find(|&&(accented, _)| accented == character)
+                .map(|&(_, plain)| plain)
+                .unwrap_or(character)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_compose_decomposed_accents() {
+        assert_eq!(normalize("Zu\u{308}rich", NormalForm::Canonical), "Zürich");
+        assert_eq!(normalize("Zürich", NormalForm::Canonical), "Zürich");
+        assert_eq!(
+            normalize("Vie\u{323}\u{302}t", NormalForm::Canonical),
+            normalize("Việt", NormalForm::Canonical)
+        );
+        assert_eq!(
+            normalize("Vie\u{302}\u{323}t", NormalForm::Canonical),
+            normalize("Việt", NormalForm::Canonical)
+        );
+    }
+
+    #[test]
+    fn should_only_decompose_compatibility_characters_in_compatibility_form() {
+        assert_eq!(normalize("ﬁle №１", NormalForm::Canonical), "ﬁle №１");
+        assert_eq!(normalize("ﬁle №１", NormalForm::Compatibility), "file No1");
+        assert_eq!(normalize("\u{212b}", NormalForm::Canonical), "Å");
+    }
+
+    #[test]
+    fn should_strip_diacritics() {
+        assert_eq!(strip_diacritics("Zürich"), "Zurich");
+        assert_eq!(strip_diacritics("Zu\u{308}rich"), "Zurich");
+        assert_eq!(strip_diacritics("Łódź Øresund"), "Lodz Oresund");
+    }
+}
@@ -0,0 +1,1348 @@
+use regex::RegexBuilder;
+
+use super::normalization::NormalForm;
+use super::prop::PropName;
+use super::sequence::Quantifier;
+use super::tokenizer::{tokenize, SpannedToken, Token};
+use super::value_matchers::*;
+use super::value_type::JsonType;
+use super::values_file::read_values;
+
+pub enum Selector {
+    Identity,
+    Prop {
+        name: PropName,
+        value: Option<JsonValueMemberMatcher>,
+    },
+    ArrayIndex(isize),
+    ArraySlice {
+        start: Option<isize>,
+        end: Option<isize>,
+    },
+    ArrayValue(JsonValueMemberMatcher),
+    ArrayMembers {
+        members: Vec<JsonValueMemberMatcher>,
+        is_whole_array: bool,
+    },
+    Sequence(Vec<Selector>),
+    QuantifiedSequence {
+        quantifier: Quantifier,
+        selectors: Vec<Selector>,
+    },
+    Alternation(Vec<Vec<Selector>>),
+    Conjunction(Vec<Vec<Selector>>),
+    Negation(Vec<Selector>),
+    Descendant(Box<Selector>),
+    Size(JsonValueMemberMatcher),
+    ValueType(JsonType),
+}
+
+pub struct ParsedPattern {
+    pub selectors: Vec<Selector>,
+    pub is_anchored: bool,
+}
+
+/// Settings which apply to the whole pattern rather than to any single matcher in it.
+#[derive(Clone, Copy, Default)]
+pub struct PatternOptions<'a> {
+    pub folding: StringFolding,
+    /// The file a bare `@` refers to, as in `{"user_id" in @}`.
+    pub values_file: Option<&'a str>,
+}
+
+#[derive(Debug)]
+pub struct ParseError {
+    pub position: usize,
+    pub expected: String,
+    pub found: Option<String>,
+}
+
+impl ParseError {
+    pub fn new(position: usize, expected: &str) -> ParseError {
+        ParseError {
+            position,
+            expected: String::from(expected),
+            found: None,
+        }
+    }
+
+    pub fn unexpected(position: usize, found: &str, expected: &str) -> ParseError {
+        ParseError {
+            position,
+            expected: String::from(expected),
+            found: Some(String::from(found)),
+        }
+    }
+
+    /// Describes the error beneath the pattern it was found in, with a caret pointing
+    /// at the column where parsing failed.
+    pub fn describe(&self, pattern: &str) -> String {
+        let column = pattern[..self.position.min(pattern.len())].chars().count();
+        format!(
+            "Invalid filter: {:?}\n{}\n{}^ expected {}{}",
+            pattern,
+            pattern,
+            " ".repeat(column),
+            self.expected,
+            self.found
+                .as_ref()
+                .map(|found| format!(", found {}", found))
+                .unwrap_or_else(|| String::from(""))
+        )
+    }
+}
+
+const PROP_MATCHING_STRATEGIES: &[&str] = &[
+    ":", "~:", "^:", "$:", "*:", "!:", ">:", ">=:", "<:", "<=:", "~",
+];
+const ARRAY_MATCHING_STRATEGIES: &[&str] =
+    &["=", "~=", "^=", "$=", "*=", "!=", ">", ">=", "<", "<=", "~"];
+const STRING_FLAGS: &str = "inka";
+const REGEX_FLAGS: &str = "i";
+const COUNT_MATCHING_STRATEGIES: &[&str] = &["=", "!=", ">", ">=", "<", "<="];
+
+struct Parser<'a> {
+    pattern: &'a str,
+    tokens: Vec<SpannedToken<'a>>,
+    index: usize,
+    folding: StringFolding,
+    values_file: Option<&'a str>,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.index).map(|spanned| &spanned.token)
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<&Token> {
+        self.tokens
+            .get(self.index + offset)
+            .map(|spanned| &spanned.token)
+    }
+
+    fn position(&self) -> usize {
+        self.tokens
+            .get(self.index)
+            .map(|spanned| spanned.position)
+            .unwrap_or_else(|| self.pattern.len())
+    }
+
+    fn next(&mut self) -> Option<&SpannedToken<'a>> {
+        self.index += 1;
+        self.tokens.get(self.index - 1)
+    }
+
+    fn error(&self, expected: &str) -> ParseError {
+        ParseError::unexpected(
+            self.position(),
+            &self
+                .peek()
+                .map(Token::describe)
+                .unwrap_or_else(|| String::from("the end of the pattern")),
+            expected,
+        )
+    }
+
+    fn expect(&mut self, token: Token, expected: &str) -> Result<(), ParseError> {
+        if self.peek() == Some(&token) {
+            self.next();
+            Ok(())
+        } else {
+            Err(self.error(expected))
+        }
+    }
+
+    /// Flags written directly after a quoted string or a regular expression, such as the
+    /// `i` in `"jeff"i`, add to the folding the whole pattern was configured with.
+    fn parse_flags(&mut self, supported_flags: &str) -> Result<StringFolding, ParseError> {
+        let mut folding = self.folding;
+        if self.is_value_set() {
+            // `"level"in [...]` is a value set, not the flags `i` and `n`.
+            return Ok(folding);
+        }
+        let (flags, position) = match (self.tokens.get(self.index - 1), self.tokens.get(self.index))
+        {
+            (
+                Some(quoted),
+                Some(SpannedToken {
+                    token: Token::Word(flags),
+                    position,
+                    ..
+                }),
+            ) if quoted.position + quoted.text.len() == *position => (flags.clone(), *position),
+            _ => return Ok(folding),
+        };
+
+        for (offset, flag) in flags.char_indices() {
+            match flag {
+                'i' => folding.ignore_case = true,
+                'n' => folding.normal_form = Some(NormalForm::Canonical),
+                'k' => folding.normal_form = Some(NormalForm::Compatibility),
+                'a' => folding.ignore_accents = true,
+                _ => {}
+            }
+            if !supported_flags.contains(flag) {
+                return Err(ParseError::unexpected(
+                    position + offset,
+                    &format!("{:?}", flag),
+                    &format!(
+                        "a matching flag (one of {})",
+                        supported_flags
+                            .chars()
+                            .map(String::from)
+                            .collect::<Vec<_>>()
+                            .join(" ")
+                    ),
+                ));
+            }
+        }
+        self.next();
+        Ok(folding)
+    }
+
+    fn is_end_of_chain(&self) -> bool {
+        matches!(
+            self.peek(),
+            None | Some(Token::RightBracket)
+                | Some(Token::RightParen)
+                | Some(Token::Pipe)
+                | Some(Token::Comma)
+        )
+    }
+
+    fn parse_alternation(&mut self) -> Result<Vec<Selector>, ParseError> {
+        let mut branches = vec![self.parse_conjunction()?];
+        while self.peek() == Some(&Token::Pipe) {
+            self.next();
+            branches.push(self.parse_conjunction()?);
+        }
+
+        Ok(if branches.len() == 1 {
+            branches.remove(0)
+        } else {
+            vec![Selector::Alternation(branches)]
+        })
+    }
+
+    fn parse_conjunction(&mut self) -> Result<Vec<Selector>, ParseError> {
+        let mut branches = vec![self.parse_chain()?];
+        while self.peek() == Some(&Token::Comma) {
+            self.next();
+            branches.push(self.parse_chain()?);
+        }
+
+        Ok(if branches.len() == 1 {
+            branches.remove(0)
+        } else {
+            vec![Selector::Conjunction(branches)]
+        })
+    }
+
+    fn is_missing_predicate(&self) -> bool {
+        matches!(
+            (self.peek(), self.peek_at(1)),
+            (Some(Token::Operator(operator)), Some(Token::Word(name)))
+                if operator == ":" && name == "missing"
+        )
+    }
+
+    fn parse_chain(&mut self) -> Result<Vec<Selector>, ParseError> {
+        let mut selectors = vec![self.parse_step()?];
+        while !self.is_end_of_chain() {
+            if self.is_missing_predicate() {
+                // `:missing` applies to the step before it, matching its input when that
+                // step finds nothing there. Only an object can be missing a property.
+                self.next();
+                self.next();
+                if let Some(missing_step) = selectors.pop() {
+                    if let Selector::Prop { .. } = missing_step {
+                        selectors.push(Selector::ValueType(JsonType::Object));
+                    }
+                    selectors.push(Selector::Negation(vec![missing_step]));
+                }
+            } else {
+                selectors.push(self.parse_step()?);
+            }
+        }
+        Ok(selectors)
+    }
+
+    fn parse_step(&mut self) -> Result<Selector, ParseError> {
+        match self.peek() {
+            Some(Token::Dot) => {
+                self.next();
+                self.parse_prop()
+            }
+            Some(Token::LeftBrace) => {
+                self.next();
+                self.parse_prop_value()
+            }
+            Some(Token::LeftBracket) => {
+                self.next();
+                self.parse_array()
+            }
+            Some(Token::LeftParen) => {
+                self.next();
+                let mut selectors = self.parse_alternation()?;
+                self.expect(Token::RightParen, "\")\" to close the group")?;
+                Ok(if selectors.len() == 1 {
+                    selectors.remove(0)
+                } else {
+                    Selector::Alternation(vec![selectors])
+                })
+            }
+            Some(Token::DotDot) => {
+                self.next();
+                let selector = match (self.peek(), self.peek_at(1)) {
+                    (Some(Token::LeftBracket), Some(Token::String(_))) => self.parse_prop()?,
+                    (Some(Token::LeftBracket), _)
+                    | (Some(Token::LeftBrace), _)
+                    | (Some(Token::LeftParen), _) => self.parse_step()?,
+                    _ if self.is_end_of_chain() => {
+                        return Err(self.error("a property name or selector after \"..\""));
+                    }
+                    _ => self.parse_prop()?,
+                };
+                Ok(Selector::Descendant(Box::new(selector)))
+            }
+            Some(Token::Operator(operator)) if operator == "!" => {
+                self.next();
+                Ok(Selector::Negation(self.parse_chain()?))
+            }
+            Some(Token::Hash) => {
+                self.next();
+                Ok(Selector::Size(self.parse_count_matcher()?))
+            }
+            Some(Token::Operator(operator)) if operator == ":" => {
+                self.next();
+                self.parse_pseudo_selector()
+            }
+            _ => {
+                Err(self
+                    .error("a selector such as \".prop\", '{\"prop\"}', \"[...]\" or \"(...)\""))
+            }
+        }
+    }
+
+    fn parse_pseudo_selector(&mut self) -> Result<Selector, ParseError> {
+        let json_type = match self.peek() {
+            Some(Token::Word(name)) => JsonType::from_name(name),
+            _ => None,
+        };
+        if let Some(json_type) = json_type {
+            self.next();
+            return Ok(Selector::ValueType(json_type));
+        }
+
+        match (self.peek(), self.peek_at(1)) {
+            (Some(Token::Word(name)), Some(Token::LeftParen)) if name == "not" => {
+                self.next();
+                self.next();
+                let selectors = self.parse_alternation()?;
+                self.expect(Token::RightParen, "\")\" to close \":not(\"")?;
+                Ok(Selector::Negation(selectors))
+            }
+            (Some(Token::Word(name)), _) if name == "missing" => {
+                Err(self.error("a selector before \":missing\""))
+            }
+            _ => {
+                Err(self
+                    .error("a pseudo selector such as \":not(...)\", \":string\" or \":missing\""))
+            }
+        }
+    }
+
+    fn parse_prop_name(&mut self) -> Option<String> {
+        let name = match self.tokens.get(self.index) {
+            Some(SpannedToken {
+                token: Token::Word(name),
+                ..
+            }) => Some(name.clone()),
+            Some(SpannedToken {
+                token: Token::String(name),
+                ..
+            }) => Some(name.clone()),
+            Some(SpannedToken {
+                token: Token::Number(_),
+                text,
+                ..
+            }) if text.chars().all(|c| c.is_alphanumeric() || c == '_') => {
+                Some(String::from(*text))
+            }
+            _ => None,
+        };
+        if name.is_some() {
+            self.next();
+        }
+        name
+    }
+
+    fn parse_prop(&mut self) -> Result<Selector, ParseError> {
+        let is_quoted = matches!(self.peek(), Some(Token::String(_)));
+        if let Some(name) = self.parse_prop_name() {
+            return Ok(Selector::Prop {
+                name: if is_quoted {
+                    PropName::literal(name, self.parse_flags(STRING_FLAGS)?)
+                } else {
+                    PropName::from_glob(name, self.folding)
+                },
+                value: None,
+            });
+        }
+
+        match (self.peek(), self.peek_at(1)) {
+            (Some(Token::LeftBracket), Some(Token::String(_))) => {
+                self.next();
+                let name = self.parse_prop_name();
+                let folding = self.parse_flags(STRING_FLAGS)?;
+                self.expect(
+                    Token::RightBracket,
+                    "\"]\" to close the bracketed property name",
+                )?;
+                Ok(Selector::Prop {
+                    name: PropName::literal(name.unwrap_or_default(), folding),
+                    value: None,
+                })
+            }
+            _ if self.is_end_of_chain() => Ok(Selector::Identity),
+            _ => Err(self.error("a property name after \".\"")),
+        }
+    }
+
+    fn parse_prop_value(&mut self) -> Result<Selector, ParseError> {
+        let mut conditions = vec![self.parse_prop_condition()?];
+        while self.peek() == Some(&Token::Comma) {
+            self.next();
+            conditions.push(self.parse_prop_condition()?);
+        }
+
+        self.expect(
+            Token::RightBrace,
+            "\"}\" to close the property value matcher",
+        )?;
+        Ok(if conditions.len() == 1 {
+            conditions.remove(0)
+        } else {
+            Selector::Conjunction(
+                conditions
+                    .into_iter()
+                    .map(|condition| vec![condition])
+                    .collect(),
+            )
+        })
+    }
+
+    fn parse_prop_condition(&mut self) -> Result<Selector, ParseError> {
+        let name = match self.peek() {
+            Some(Token::String(_)) => {
+                let name = self.parse_prop_name().unwrap_or_default();
+                PropName::from_glob(name, self.parse_flags(STRING_FLAGS)?)
+            }
+            _ => return Err(self.error("a quoted property name")),
+        };
+
+        let value = match self.peek() {
+            Some(Token::RightBrace) | Some(Token::Comma) => None,
+            _ => Some(self.parse_value_matcher(PROP_MATCHING_STRATEGIES)?),
+        };
+
+        Ok(Selector::Prop { name, value })
+    }
+
+    fn parse_array(&mut self) -> Result<Selector, ParseError> {
+        let selector = match self.peek() {
+            Some(Token::Number(_)) => {
+                let index = self.parse_array_index()?;
+                if self.peek() == Some(&Token::Operator(String::from(":"))) {
+                    self.next();
+                    self.parse_array_slice(Some(index))?
+                } else {
+                    Selector::ArrayIndex(index)
+                }
+            }
+            Some(Token::Operator(operator))
+                if operator == ":"
+                    && matches!(
+                        self.peek_at(1),
+                        Some(Token::Number(_)) | Some(Token::RightBracket)
+                    ) =>
+            {
+                self.next();
+                self.parse_array_slice(None)?
+            }
+            Some(Token::Operator(operator)) if operator == "==" => {
+                self.next();
+                let mut members = vec![];
+                while self.peek() != Some(&Token::RightBracket) {
+                    if !members.is_empty() {
+                        self.expect(Token::Comma, "\",\" or \"]\" in the list of members")?;
+                    }
+                    members.push(JsonValueMemberMatcher::Exact(self.parse_literal()?));
+                }
+                Selector::ArrayMembers {
+                    members,
+                    is_whole_array: true,
+                }
+            }
+            Some(Token::Operator(operator)) if operator != ":" && operator != "!" => {
+                self.parse_array_value()?
+            }
+            _ if self.is_value_set() => self.parse_array_value()?,
+            Some(Token::Word(word))
+                if (word == "all" || word == "none")
+                    && self.peek_at(1) == Some(&Token::Operator(String::from(":"))) =>
+            {
+                let quantifier = if word == "all" {
+                    Quantifier::All
+                } else {
+                    Quantifier::None
+                };
+                self.next();
+                self.next();
+                Selector::QuantifiedSequence {
+                    quantifier,
+                    selectors: self.parse_alternation()?,
+                }
+            }
+            Some(Token::Hash) => {
+                self.next();
+                let quantifier = Quantifier::Count(self.parse_count_matcher()?);
+                self.expect(
+                    Token::Operator(String::from(":")),
+                    "\":\" between the count and the pattern",
+                )?;
+                Selector::QuantifiedSequence {
+                    quantifier,
+                    selectors: self.parse_alternation()?,
+                }
+            }
+            _ => Selector::Sequence(self.parse_alternation()?),
+        };
+
+        self.expect(Token::RightBracket, "\"]\" to close the array matcher")?;
+        Ok(selector)
+    }
+
+    fn parse_array_value(&mut self) -> Result<Selector, ParseError> {
+        let member = self.parse_value_matcher(ARRAY_MATCHING_STRATEGIES)?;
+        if self.peek() != Some(&Token::Comma) {
+            return Ok(Selector::ArrayValue(member));
+        }
+
+        let mut members = vec![member];
+        while self.peek() == Some(&Token::Comma) {
+            self.next();
+            members.push(self.parse_value_matcher(ARRAY_MATCHING_STRATEGIES)?);
+        }
+        Ok(Selector::ArrayMembers {
+            members,
+            is_whole_array: false,
+        })
+    }
+
+    fn parse_array_index(&mut self) -> Result<isize, ParseError> {
+        let text = self.tokens[self.index].text;
+        let digits = text.strip_prefix('-').unwrap_or(text);
+        match text.parse::<isize>() {
+            Ok(index) if digits.chars().all(|c| c.is_ascii_digit()) => {
+                self.next();
+                Ok(index)
+            }
+            _ => Err(self.error("an integer array index")),
+        }
+    }
+
+    fn parse_array_slice(&mut self, start: Option<isize>) -> Result<Selector, ParseError> {
+        let end = match self.peek() {
+            Some(Token::Number(_)) => Some(self.parse_array_index()?),
+            _ => None,
+        };
+        Ok(Selector::ArraySlice { start, end })
+    }
+
+    fn parse_count_matcher(&mut self) -> Result<JsonValueMemberMatcher, ParseError> {
+        let value_index = self.index + 1;
+        let count_matcher = self.parse_value_matcher(COUNT_MATCHING_STRATEGIES)?;
+        match count_matcher {
+            JsonValueMemberMatcher::Exact(JsonValueMatcher::Number(_))
+            | JsonValueMemberMatcher::NotEqual(JsonValueMatcher::Number(_))
+            | JsonValueMemberMatcher::GreaterThan(_)
+            | JsonValueMemberMatcher::GreaterThanOrEqual(_)
+            | JsonValueMemberMatcher::LessThan(_)
+            | JsonValueMemberMatcher::LessThanOrEqual(_)
+            | JsonValueMemberMatcher::Range(_) => Ok(count_matcher),
+            _ => {
+                self.index = value_index;
+                Err(self.error("a number or range to compare the count with"))
+            }
+        }
+    }
+
+    fn parse_value_matcher(
+        &mut self,
+        strategies: &[&str],
+    ) -> Result<JsonValueMemberMatcher, ParseError> {
+        if self.is_value_set() {
+            return self.parse_value_set();
+        }
+
+        let strategy_position = self.position();
+        let strategy = match self.peek() {
+            Some(Token::Operator(strategy)) if strategies.contains(&strategy.as_str()) => {
+                strategy.clone()
+            }
+            _ => {
+                return Err(self.error(&format!(
+                    "a matching strategy (one of {})",
+                    strategies.join(" ")
+                )))
+            }
+        };
+        self.next();
+
+        let value_position = self.position();
+        if strategy == "~" {
+            let regex =
+                match self.peek() {
+                    Some(Token::Regex(regex)) => regex.clone(),
+                    Some(Token::Number(_)) => return self.parse_fuzzy_matcher(strategies),
+                    _ => return Err(self.error(
+                        "a regular expression such as /^value$/ or an edit distance after \"~\"",
+                    )),
+                };
+            self.next();
+            let folding = self.parse_flags(REGEX_FLAGS)?;
+            return RegexBuilder::new(&regex)
+                .case_insensitive(folding.ignore_case)
+                .build()
+                .map(JsonValueMemberMatcher::Regex)
+                .map_err(|_| ParseError::new(value_position, "a valid regular expression"));
+        }
+
+        let value = match (self.peek(), self.peek_at(1)) {
+            (Some(Token::DotDot), _) => {
+                return self.parse_range(&strategy, None, strategy_position)
+            }
+            (Some(Token::Number(number)), Some(Token::DotDot)) => {
+                let start = JsonValueMatcher::Number(*number);
+                self.next();
+                return self.parse_range(&strategy, Some(start), strategy_position);
+            }
+            _ => self.parse_literal()?,
+        };
+
+        member_matcher_for(&strategy, value)
+            .ok_or_else(|| ParseError::new(strategy_position, "a valid matching strategy"))
+    }
+
+    fn is_value_set(&self) -> bool {
+        matches!(
+            (self.peek(), self.peek_at(1)),
+            (Some(Token::Word(word)), Some(Token::LeftBracket))
+            | (Some(Token::Word(word)), Some(Token::ValuesFile(_))) if word == "in"
+        )
+    }
+
+    /// Set membership, such as `in ["error","fatal"]`, matches any one of the listed values.
+    fn parse_value_set(&mut self) -> Result<JsonValueMemberMatcher, ParseError> {
+        self.next();
+        if let Some(Token::ValuesFile(path)) = self.peek() {
+            let path = path.clone();
+            return self.parse_values_file(&path);
+        }
+
+        self.next();
+        let mut values = JsonValueSet::default();
+        let mut is_first = true;
+        while self.peek() != Some(&Token::RightBracket) {
+            if !is_first {
+                self.expect(Token::Comma, "\",\" or \"]\" in the set of values")?;
+            }
+            is_first = false;
+
+            let value_index = self.index;
+            let value = self.parse_literal()?;
+            if !values.insert(&value) {
+                self.index = value_index;
+                return Err(self.error("a string, number, boolean or null in the set of values"));
+            }
+        }
+        self.next();
+        Ok(JsonValueMemberMatcher::In(values))
+    }
+
+    /// Values files hold too many values to list in the pattern, such as the thousands of
+    /// account ids in `{"account_id" in @accounts.txt}`.
+    fn parse_values_file(&mut self, path: &str) -> Result<JsonValueMemberMatcher, ParseError> {
+        let path = match (path, self.values_file) {
+            ("", Some(values_file)) => values_file,
+            ("", None) => {
+                return Err(ParseError::new(
+                    self.position(),
+                    "a path after \"@\", or a file given with --values-file",
+                ))
+            }
+            (path, _) => path,
+        };
+
+        let values = read_values(path).map_err(|error| {
+            ParseError::unexpected(
+                self.position(),
+                &format!("{:?} ({})", path, error),
+                "a readable values file",
+            )
+        })?;
+        self.next();
+
+        let mut set = JsonValueSet::default();
+        for value in values {
+            set.insert(&string_matcher(value, self.folding));
+        }
+        Ok(JsonValueMemberMatcher::In(set))
+    }
+
+    /// Approximate matchers, such as `~2:"jef goldblum"`, allow up to the specified number
+    /// of single character insertions, deletions and substitutions.
+    fn parse_fuzzy_matcher(
+        &mut self,
+        strategies: &[&str],
+    ) -> Result<JsonValueMemberMatcher, ParseError> {
+        let text = self.tokens[self.index].text;
+        let max_distance = match text.parse::<usize>() {
+            Ok(max_distance) if text.chars().all(|c| c.is_ascii_digit()) => max_distance,
+            _ => return Err(self.error("a whole number of edits")),
+        };
+        self.next();
+
+        let separator = if strategies.contains(&":") { ":" } else { "=" };
+        self.expect(
+            Token::Operator(String::from(separator)),
+            &format!("\"{}\" after the edit distance", separator),
+        )?;
+
+        let value_index = self.index;
+        match self.parse_literal()? {
+            value @ JsonValueMatcher::String(_) | value @ JsonValueMatcher::FoldedString(_, _) => {
+                Ok(JsonValueMemberMatcher::Fuzzy {
+                    value,
+                    max_distance,
+                })
+            }
+            _ => {
+                self.index = value_index;
+                Err(self.error("a string to match approximately"))
+            }
+        }
+    }
+
+    fn parse_literal(&mut self) -> Result<JsonValueMatcher, ParseError> {
+        let value = match self.peek() {
+            Some(Token::Number(number)) => JsonValueMatcher::Number(*number),
+            Some(Token::String(value)) => {
+                let value = value.clone();
+                self.next();
+                let folding = self.parse_flags(STRING_FLAGS)?;
+                return Ok(string_matcher(value, folding));
+            }
+            Some(Token::Word(word)) if word == "true" => JsonValueMatcher::Boolean(true),
+            Some(Token::Word(word)) if word == "false" => JsonValueMatcher::Boolean(false),
+            Some(Token::Word(word)) if word == "null" => JsonValueMatcher::Null,
+            Some(Token::LeftBrace) => {
+                self.next();
+                return self.parse_object_literal();
+            }
+            Some(Token::LeftBracket) => {
+                self.next();
+                return self.parse_array_literal();
+            }
+            _ => return Err(self.error("a JSON value")),
+        };
+        self.next();
+        Ok(value)
+    }
+
+    fn parse_object_literal(&mut self) -> Result<JsonValueMatcher, ParseError> {
+        let mut members = vec![];
+        while self.peek() != Some(&Token::RightBrace) {
+            if !members.is_empty() {
+                self.expect(Token::Comma, "\",\" or \"}\" in the object value")?;
+            }
+            let name = match self.peek() {
+                Some(Token::String(name)) => name.clone(),
+                _ => return Err(self.error("a quoted property name")),
+            };
+            self.next();
+            let name = PropName::literal(name, self.parse_flags(STRING_FLAGS)?);
+            self.expect(
+                Token::Operator(String::from(":")),
+                "\":\" after the property name",
+            )?;
+            members.push((name, self.parse_literal()?));
+        }
+        self.next();
+        Ok(JsonValueMatcher::Object(members))
+    }
+
+    fn parse_array_literal(&mut self) -> Result<JsonValueMatcher, ParseError> {
+        let mut members = vec![];
+        while self.peek() != Some(&Token::RightBracket) {
+            if !members.is_empty() {
+                self.expect(Token::Comma, "\",\" or \"]\" in the array value")?;
+            }
+            members.push(self.parse_literal()?);
+        }
+        self.next();
+        Ok(JsonValueMatcher::Array(members))
+    }
+
+    fn parse_range(
+        &mut self,
+        strategy: &str,
+        start: Option<JsonValueMatcher>,
+        strategy_position: usize,
+    ) -> Result<JsonValueMemberMatcher, ParseError> {
+        self.next();
+        let is_end_inclusive = match self.peek() {
+            Some(Token::Operator(operator)) if operator == "<" => {
+                self.next();
+                false
+            }
+            Some(Token::Operator(operator)) if operator == "=" => {
+                self.next();
+                true
+            }
+            _ => true,
+        };
+        let end = match self.peek() {
+            Some(Token::Number(number)) => {
+                let number = JsonValueMatcher::Number(*number);
+                self.next();
+                Some(number)
+            }
+            _ => None,
+        };
+
+        match (strategy, &start, &end) {
+            (_, None, None) => Err(self.error("a number to bound the range")),
+            ("=", _, _) | (":", _, _) => Ok(JsonValueMemberMatcher::Range(JsonValueRange {
+                start,
+                end,
+                is_end_inclusive,
+            })),
+            _ => Err(ParseError::new(
+                strategy_position,
+                "the exact matching strategy (\":\" or \"=\") before a range",
+            )),
+        }
+    }
+}
+
+fn string_matcher(value: String, folding: StringFolding) -> JsonValueMatcher {
+    if folding.is_exact() {
+        JsonValueMatcher::String(value)
+    } else {
+        JsonValueMatcher::FoldedString(folding.fold(&value), folding)
+    }
+}
+
+pub fn parse<'a>(
+    pattern: &'a str,
+    options: &PatternOptions<'a>,
+) -> Result<ParsedPattern, ParseError> {
+    let mut parser = Parser {
+        pattern,
+        tokens: tokenize(pattern)?,
+        index: 0,
+        folding: options.folding,
+        values_file: options.values_file,
+    };
+
+    let is_anchored = parser.peek() == Some(&Token::Anchor);
+    if is_anchored {
+        parser.next();
+    }
+
+    let selectors = parser.parse_alternation()?;
+    match parser.peek() {
+        None => Ok(ParsedPattern {
+            selectors,
+            is_anchored,
+        }),
+        Some(_) => Err(parser.error("the end of the pattern")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_selectors(pattern: &str) -> Result<Vec<Selector>, ParseError> {
+        parse(pattern, &PatternOptions::default()).map(|parsed| parsed.selectors)
+    }
+
+    fn error_position(pattern: &str) -> Option<usize> {
+        parse(pattern, &PatternOptions::default())
+            .err()
+            .map(|err| err.position)
+    }
+
+    #[test]
+    fn should_parse_selectors_following_a_sequence() {
+        match parse_selectors("[.name].craft").unwrap().as_slice() {
+            [Selector::Sequence(inner), Selector::Prop { name, value: None }] => {
+                assert!(matches!(inner.as_slice(), [Selector::Prop { .. }]));
+                assert!(matches!(name, PropName::Exact(name) if name == "craft"));
+            }
+            _ => panic!("Invalid result"),
+        }
+
+        assert!(matches!(
+            parse_selectors("[.a][.b]").unwrap().as_slice(),
+            [Selector::Sequence(_), Selector::Sequence(_)]
+        ));
+        assert!(matches!(
+            parse_selectors(r#"[{"name":"]\"["}].id"#)
+                .unwrap()
+                .as_slice(),
+            [Selector::Sequence(_), Selector::Prop { .. }]
+        ));
+        assert!(matches!(
+            parse_selectors(r#"[{"id"~/^[a-z]+\/]/}]"#)
+                .unwrap()
+                .as_slice(),
+            [Selector::Sequence(_)]
+        ));
+    }
+
+    #[test]
+    fn should_parse_nested_sequences() {
+        match parse_selectors(".people[.roles[0]]").unwrap().as_slice() {
+            [Selector::Prop { .. }, Selector::Sequence(inner)] => assert!(matches!(
+                inner.as_slice(),
+                [Selector::Prop { .. }, Selector::ArrayIndex(0)]
+            )),
+            _ => panic!("Invalid result"),
+        }
+    }
+
+    #[test]
+    fn should_parse_array_index_in_decimal() {
+        assert!(matches!(
+            parse_selectors("[10]").unwrap().as_slice(),
+            [Selector::ArrayIndex(10)]
+        ));
+    }
+
+    #[test]
+    fn should_parse_numeric_prop_names_as_separate_steps() {
+        match parse_selectors(".0.1").unwrap().as_slice() {
+            [Selector::Prop { name: first, .. }, Selector::Prop { name: second, .. }] => {
+                assert!(matches!(first, PropName::Exact(name) if name == "0"));
+                assert!(matches!(second, PropName::Exact(name) if name == "1"));
+            }
+            _ => panic!("Invalid result"),
+        }
+        assert_eq!(parse_selectors(".a.12.5").unwrap().len(), 3);
+    }
+
+    #[test]
+    fn should_parse_alternation_within_a_group() {
+        match parse_selectors(".user(.email|.phone.mobile)")
+            .unwrap()
+            .as_slice()
+        {
+            [Selector::Prop { .. }, Selector::Alternation(branches)] => {
+                assert!(matches!(branches[0].as_slice(), [Selector::Prop { .. }]));
+                assert!(matches!(
+                    branches[1].as_slice(),
+                    [Selector::Prop { .. }, Selector::Prop { .. }]
+                ));
+            }
+            _ => panic!("Invalid result"),
+        }
+
+        assert!(matches!(
+            parse_selectors(r#"{"level":"error"}|{"level":"fatal"}"#)
+                .unwrap()
+                .as_slice(),
+            [Selector::Alternation(_)]
+        ));
+        assert!(matches!(
+            parse_selectors("(.name).title").unwrap().as_slice(),
+            [Selector::Prop { .. }, Selector::Prop { .. }]
+        ));
+        assert!(matches!(
+            parse_selectors("[.name|.title]").unwrap().as_slice(),
+            [Selector::Sequence(_)]
+        ));
+    }
+
+    #[test]
+    fn should_point_at_unclosed_and_empty_groups() {
+        assert_eq!(error_position(".user(.email|.phone"), Some(19));
+        assert_eq!(error_position(".user(.email|)"), Some(13));
+        assert_eq!(error_position(".name)"), Some(5));
+    }
+
+    #[test]
+    fn should_bind_conjunction_tighter_than_alternation() {
+        match parse_selectors(".a,.b|.c").unwrap().as_slice() {
+            [Selector::Alternation(branches)] => {
+                assert!(matches!(branches[0].as_slice(), [Selector::Conjunction(_)]));
+                assert!(matches!(branches[1].as_slice(), [Selector::Prop { .. }]));
+            }
+            _ => panic!("Invalid result"),
+        }
+
+        match parse_selectors(r#"{"level":"error", "service"}"#)
+            .unwrap()
+            .as_slice()
+        {
+            [Selector::Conjunction(conditions)] => assert!(matches!(
+                conditions[1].as_slice(),
+                [Selector::Prop { value: None, .. }]
+            )),
+            _ => panic!("Invalid result"),
+        }
+    }
+
+    #[test]
+    fn should_point_at_trailing_commas_in_conjunctions() {
+        assert_eq!(error_position(r#"{"level":"error",}"#), Some(17));
+        assert_eq!(error_position(".req(.method,)"), Some(13));
+    }
+
+    #[test]
+    fn should_parse_negation_of_the_rest_of_the_chain() {
+        match parse_selectors(".order(.items, !.shipping.address)")
+            .unwrap()
+            .as_slice()
+        {
+            [Selector::Prop { .. }, Selector::Conjunction(branches)] => {
+                match branches[1].as_slice() {
+                    [Selector::Negation(inner)] => assert_eq!(inner.len(), 2),
+                    _ => panic!("Invalid result"),
+                }
+            }
+            _ => panic!("Invalid result"),
+        }
+
+        assert!(matches!(
+            parse_selectors(".user:not(.email|.phone).name")
+                .unwrap()
+                .as_slice(),
+            [
+                Selector::Prop { .. },
+                Selector::Negation(_),
+                Selector::Prop { .. }
+            ]
+        ));
+        assert!(matches!(
+            parse_selectors("[!=5]").unwrap().as_slice(),
+            [Selector::ArrayValue(_)]
+        ));
+    }
+
+    #[test]
+    fn should_point_at_incomplete_negations() {
+        assert_eq!(error_position(".user!"), Some(6));
+        assert_eq!(error_position(".user:nope(.email)"), Some(6));
+        assert_eq!(error_position(".user:not(.email"), Some(16));
+    }
+
+    #[test]
+    fn should_parse_globs_in_unquoted_and_brace_prop_names() {
+        for pattern in &[".*", ".http_*", ".*_id.value", r#"{"*_id":"abc"}"#] {
+            assert!(matches!(
+                parse_selectors(pattern).unwrap().first(),
+                Some(Selector::Prop {
+                    name: PropName::Glob(_),
+                    ..
+                })
+            ));
+        }
+
+        for pattern in &[r#"."*""#, r#".["http_*"]"#, r#"{"request_id":"abc"}"#] {
+            assert!(matches!(
+                parse_selectors(pattern).unwrap().first(),
+                Some(Selector::Prop {
+                    name: PropName::Exact(_),
+                    ..
+                })
+            ));
+        }
+    }
+
+    #[test]
+    fn should_parse_descendant_steps_and_anchor() {
+        let parsed = parse("^.request..token", &PatternOptions::default()).unwrap();
+        assert!(parsed.is_anchored);
+        assert!(matches!(
+            parsed.selectors.as_slice(),
+            [Selector::Prop { .. }, Selector::Descendant(step)]
+                if matches!(**step, Selector::Prop { .. })
+        ));
+        assert!(
+            !parse(".request..token", &PatternOptions::default())
+                .unwrap()
+                .is_anchored
+        );
+
+        for pattern in &[
+            r#"..["id"]"#,
+            "..*",
+            r#"..{"level":"error"}"#,
+            "..[0]",
+            "..(.a|.b)",
+        ] {
+            assert!(matches!(
+                parse_selectors(pattern).unwrap().as_slice(),
+                [Selector::Descendant(_)]
+            ));
+        }
+    }
+
+    #[test]
+    fn should_point_at_misplaced_descendant_steps_and_anchors() {
+        assert_eq!(error_position(".."), Some(2));
+        assert_eq!(error_position(".a.^.b"), Some(3));
+    }
+
+    #[test]
+    fn should_parse_negative_indices_and_slices() {
+        assert!(matches!(
+            parse_selectors("[-1]").unwrap().as_slice(),
+            [Selector::ArrayIndex(-1)]
+        ));
+        assert!(matches!(
+            parse_selectors("[0:3]").unwrap().as_slice(),
+            [Selector::ArraySlice {
+                start: Some(0),
+                end: Some(3)
+            }]
+        ));
+        assert!(matches!(
+            parse_selectors("[-5:]").unwrap().as_slice(),
+            [Selector::ArraySlice {
+                start: Some(-5),
+                end: None
+            }]
+        ));
+        assert!(matches!(
+            parse_selectors("[:-1]").unwrap().as_slice(),
+            [Selector::ArraySlice {
+                start: None,
+                end: Some(-1)
+            }]
+        ));
+    }
+
+    #[test]
+    fn should_point_at_non_integer_slice_bounds() {
+        assert_eq!(error_position("[0:1e2]"), Some(3));
+    }
+
+    #[test]
+    fn should_parse_quantified_sequences() {
+        for pattern in &[
+            "[all: .ok]",
+            r#"[none: {"status":"failed"}]"#,
+            "[#>=3: .name]",
+        ] {
+            assert!(matches!(
+                parse_selectors(pattern).unwrap().as_slice(),
+                [Selector::QuantifiedSequence { .. }]
+            ));
+        }
+
+        assert!(matches!(
+            parse_selectors("[#=0..5:.a|.b]").unwrap().as_slice(),
+            [Selector::QuantifiedSequence {
+                quantifier: Quantifier::Count(JsonValueMemberMatcher::Range(_)),
+                ..
+            }]
+        ));
+    }
+
+    #[test]
+    fn should_point_at_incomplete_quantifiers() {
+        assert_eq!(error_position("[all .ok]"), Some(1));
+        assert_eq!(error_position(r#"[#="3": .ok]"#), Some(3));
+        assert_eq!(error_position("[#>3 .ok]"), Some(5));
+    }
+
+    #[test]
+    fn should_parse_size_predicates_as_steps() {
+        assert!(matches!(
+            parse_selectors(".items#=0").unwrap().as_slice(),
+            [
+                Selector::Prop { .. },
+                Selector::Size(JsonValueMemberMatcher::Exact(_))
+            ]
+        ));
+        assert!(matches!(
+            parse_selectors(".tags#>5[.name]").unwrap().as_slice(),
+            [
+                Selector::Prop { .. },
+                Selector::Size(JsonValueMemberMatcher::GreaterThan(_)),
+                Selector::Sequence(_)
+            ]
+        ));
+    }
+
+    #[test]
+    fn should_point_at_invalid_size_predicates() {
+        assert_eq!(error_position(".items#~=0"), Some(7));
+        assert_eq!(error_position(".items#=true"), Some(8));
+    }
+
+    #[test]
+    fn should_parse_type_predicates() {
+        assert!(matches!(
+            parse_selectors(".user_id:string").unwrap().as_slice(),
+            [Selector::Prop { .. }, Selector::ValueType(JsonType::String)]
+        ));
+        assert!(matches!(
+            parse_selectors("[:number]").unwrap().as_slice(),
+            [Selector::Sequence(_)]
+        ));
+        assert!(matches!(
+            parse_selectors("[!.a]").unwrap().as_slice(),
+            [Selector::Sequence(_)]
+        ));
+    }
+
+    #[test]
+    fn should_parse_missing_as_negation_of_the_preceding_step() {
+        match parse_selectors(".user.email:missing").unwrap().as_slice() {
+            [Selector::Prop { .. }, Selector::ValueType(JsonType::Object), Selector::Negation(inner)] =>
+            {
+                assert!(matches!(inner.as_slice(), [Selector::Prop { .. }]))
+            }
+            _ => panic!("Invalid result"),
+        }
+    }
+
+    #[test]
+    fn should_point_at_unknown_or_detached_predicates() {
+        assert_eq!(error_position(":missing"), Some(1));
+        assert_eq!(error_position(".id:text"), Some(4));
+    }
+
+    #[test]
+    fn should_parse_nested_object_and_array_values() {
+        match parse_selectors(r#"{"user":{"role":"admin","tags":["a",{}]}}"#)
+            .unwrap()
+            .as_slice()
+        {
+            [Selector::Prop {
+                value: Some(JsonValueMemberMatcher::Exact(JsonValueMatcher::Object(members))),
+                ..
+            }] => {
+                assert_eq!(members.len(), 2);
+                assert!(matches!(
+                    &members[1],
+                    (PropName::Exact(name), JsonValueMatcher::Array(tags))
+                        if name == "tags" && tags.len() == 2
+                ));
+            }
+            _ => panic!("Invalid result"),
+        }
+
+        assert!(matches!(
+            parse_selectors("[=[]]").unwrap().as_slice(),
+            [Selector::ArrayValue(JsonValueMemberMatcher::Exact(
+                JsonValueMatcher::Array(_)
+            ))]
+        ));
+    }
+
+    #[test]
+    fn should_point_at_invalid_nested_values() {
+        assert_eq!(error_position(r#"{"user":{"role" "admin"}}"#), Some(16));
+        assert_eq!(error_position(r#"{"tags":["a" "b"]}"#), Some(13));
+        assert_eq!(error_position(r#"{"user":{role:1}}"#), Some(9));
+    }
+
+    #[test]
+    fn should_point_at_invalid_member_lists() {
+        assert_eq!(error_position(r#"[="login", "logout"]"#), Some(11));
+        assert_eq!(error_position(r#"[=="a" "b"]"#), Some(7));
+    }
+
+    #[test]
+    fn should_parse_flags_before_a_contains_strategy() {
+        assert!(matches!(
+            parse_selectors(r#"{"name"i*:"jeff"}"#).unwrap().as_slice(),
+            [Selector::Prop {
+                name: PropName::Folded(_, _),
+                value: Some(JsonValueMemberMatcher::Contains(_)),
+            }]
+        ));
+    }
+
+    #[test]
+    fn should_point_at_unknown_or_detached_flags() {
+        assert_eq!(error_position(r#"{"name":"jeff"x}"#), Some(14));
+        assert_eq!(error_position(r#"{"name":"jeff"ix}"#), Some(15));
+        assert_eq!(error_position(r#"{"name":"jeff" i}"#), Some(15));
+    }
+
+    #[test]
+    fn should_point_at_string_only_flags_on_regexes() {
+        assert_eq!(error_position(r#"{"name"~/jeff/ia}"#), Some(15));
+    }
+
+    #[test]
+    fn should_point_at_invalid_fuzzy_matchers() {
+        assert_eq!(error_position(r#"{"name"~1.5:"jef"}"#), Some(8));
+        assert_eq!(error_position(r#"{"name"~2="jef"}"#), Some(9));
+        assert_eq!(error_position(r#"[~2:"jef"]"#), Some(3));
+        assert_eq!(error_position(r#"[~2=42]"#), Some(4));
+    }
+
+    #[test]
+    fn should_parse_value_sets_directly_after_a_key() {
+        for pattern in &[r#"{"level"in ["error"]}"#, r#"{"level"i in ["error"]}"#] {
+            assert!(matches!(
+                parse_selectors(pattern).unwrap().as_slice(),
+                [Selector::Prop {
+                    value: Some(JsonValueMemberMatcher::In(_)),
+                    ..
+                }]
+            ));
+        }
+    }
+
+    #[test]
+    fn should_point_at_invalid_value_sets() {
+        assert_eq!(error_position(r#"{"level" in ["error",{}]}"#), Some(21));
+        assert_eq!(error_position("[in [401 403]]"), Some(9));
+    }
+
+    #[test]
+    fn should_point_at_the_column_which_failed_to_parse() {
+        assert_eq!(error_position("[.name"), Some(6));
+        assert_eq!(error_position("[]"), Some(1));
+        assert_eq!(error_position(".name]"), Some(5));
+        assert_eq!(error_position(".people.[0]"), Some(8));
+        assert_eq!(error_position(r#"{name}"#), Some(1));
+        assert_eq!(error_position(r#"{"status"^:1..5}"#), Some(9));
+        assert_eq!(error_position(r#"{"status"=:5}"#), Some(9));
+        assert_eq!(error_position(r#"{"msg"~/(/}"#), Some(7));
+        assert_eq!(error_position("[1.5]"), Some(1));
+    }
+
+    #[test]
+    fn should_describe_error_with_a_caret_under_the_pattern() {
+        let pattern = ".people[.name";
+        assert_eq!(
+            parse(pattern, &PatternOptions::default()).err().unwrap().describe(pattern),
+            concat!(
+                "Invalid filter: \".people[.name\"\n",
+                ".people[.name\n",
+                "             ^ expected \"]\" to close the array matcher, found the end of the pattern"
+            )
+        );
+    }
+}
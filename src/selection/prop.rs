@@ -2,179 +2,98 @@ use json::JsonValue;
 use regex::Regex;
 
 use super::value_matchers::*;
-use super::{SelectionLens, SelectionLensParser};
+use super::SelectionLens;
 
-struct Prop {
-    name: String,
-    value: Option<JsonValueMemberMatcher>,
+pub enum PropName {
+    Exact(String),
+    Glob(Regex),
+    Folded(Box<PropName>, StringFolding),
 }
 
-impl Prop {
-    pub fn prop_value_matches_exact<'a, 'b>(
-        prop: &'a JsonValue,
-        prop_value_matcher: &'b JsonValueMatcher,
-    ) -> Option<&'a JsonValue> {
-        match (prop, prop_value_matcher) {
-            (&JsonValue::String(ref string_prop), &JsonValueMatcher::String(ref prop_value)) => {
-                Some(prop).filter(|_| string_prop.eq(prop_value))
-            }
-            (&JsonValue::Short(ref string_prop), &JsonValueMatcher::String(ref prop_value)) => {
-                Some(prop).filter(|_| string_prop.eq(prop_value))
-            }
-            (&JsonValue::Number(ref number_prop), &JsonValueMatcher::Number(ref prop_value)) => {
-                Some(prop).filter(|_| number_prop.eq(prop_value))
-            }
-            (&JsonValue::Boolean(ref bool_prop), &JsonValueMatcher::Boolean(ref prop_value)) => {
-                Some(prop).filter(|_| bool_prop.eq(prop_value))
-            }
-            (&JsonValue::Null, &JsonValueMatcher::Null) => Some(prop),
-            (_, _) => None,
-        }
-    }
-
-    pub fn prop_value_contains_exact<'a, 'b>(
-        prop: &'a JsonValue,
-        prop_value_matcher: &'b JsonValueMatcher,
-    ) -> Option<&'a JsonValue> {
-        match (prop, prop_value_matcher) {
-            (&JsonValue::String(ref string_prop), &JsonValueMatcher::String(ref prop_value)) => {
-                Some(prop).filter(|_| {
-                    string_prop
-                        .split_whitespace()
-                        .any(|string_prop| string_prop.eq(prop_value))
-                })
-            }
-            (&JsonValue::Short(ref string_prop), &JsonValueMatcher::String(ref prop_value)) => {
-                Some(prop).filter(|_| {
-                    string_prop
-                        .split_whitespace()
-                        .any(|string_prop| string_prop.eq(prop_value))
-                })
-            }
-            (_, _) => None,
+impl PropName {
+    /// Quoted names match exactly, unless the pattern asks for their case to be ignored.
+    pub fn literal(name: String, folding: StringFolding) -> PropName {
+        if folding.is_exact() {
+            PropName::Exact(name)
+        } else {
+            PropName::Folded(Box::new(PropName::Exact(folding.fold(&name))), folding)
         }
     }
 
-    pub fn prop_value_is_prefixed_by<'a, 'b>(
-        prop: &'a JsonValue,
-        prop_value_matcher: &'b JsonValueMatcher,
-    ) -> Option<&'a JsonValue> {
-        match (prop, prop_value_matcher) {
-            (&JsonValue::String(ref string_prop), &JsonValueMatcher::String(ref prop_value)) => {
-                Some(prop).filter(|_| string_prop.starts_with(prop_value))
-            }
-            (&JsonValue::Short(ref string_prop), &JsonValueMatcher::String(ref prop_value)) => {
-                Some(prop).filter(|_| string_prop.starts_with(prop_value))
+    /// Names containing a `*` become globs matching any run of characters in its place,
+    /// with a backslash escaping the character which follows it.
+    pub fn from_glob(name: String, folding: StringFolding) -> PropName {
+        if !name.contains('*') {
+            return PropName::literal(name, folding);
+        }
+
+        let mut pattern = String::from("(?s)^");
+        let name = folding.fold(&name);
+        let mut characters = name.chars();
+        while let Some(character) = characters.next() {
+            match character {
+                '*' => pattern.push_str(".*"),
+                '\\' => pattern.push_str(&regex::escape(
+                    &characters.next().unwrap_or('\\').to_string(),
+                )),
+                character => pattern.push_str(&regex::escape(&character.to_string())),
             }
-            (_, _) => None,
+        }
+        pattern.push('$');
+
+        let glob = PropName::Glob(Regex::new(&pattern).expect("escaped glob is a valid regex"));
+        if folding.is_exact() {
+            glob
+        } else {
+            PropName::Folded(Box::new(glob), folding)
         }
     }
 
-    pub fn prop_value_is_suffixed_by<'a, 'b>(
-        prop: &'a JsonValue,
-        prop_value_matcher: &'b JsonValueMatcher,
-    ) -> Option<&'a JsonValue> {
-        match (prop, prop_value_matcher) {
-            (&JsonValue::String(ref string_prop), &JsonValueMatcher::String(ref prop_value)) => {
-                Some(prop).filter(|_| string_prop.ends_with(prop_value))
-            }
-            (&JsonValue::Short(ref string_prop), &JsonValueMatcher::String(ref prop_value)) => {
-                Some(prop).filter(|_| string_prop.ends_with(prop_value))
-            }
-            (_, _) => None,
+    pub fn matches(&self, name: &str) -> bool {
+        match self {
+            PropName::Exact(exact_name) => exact_name == name,
+            PropName::Glob(glob) => glob.is_match(name),
+            PropName::Folded(prop_name, folding) => prop_name.matches(&folding.fold(name)),
         }
     }
+}
 
-    pub fn prop_value_contains<'a, 'b>(
-        prop: &'a JsonValue,
-        prop_value_matcher: &'b JsonValueMatcher,
-    ) -> Option<&'a JsonValue> {
-        match (prop, prop_value_matcher) {
-            (&JsonValue::String(ref string_prop), &JsonValueMatcher::String(ref prop_value)) => {
-                Some(prop).filter(|_| string_prop.contains(prop_value))
-            }
-            (&JsonValue::Short(ref string_prop), &JsonValueMatcher::String(ref prop_value)) => {
-                Some(prop).filter(|_| string_prop.contains(prop_value))
+pub struct Prop {
+    pub name: PropName,
+    pub value: Option<JsonValueMemberMatcher>,
+}
+
+impl Prop {
+    fn prop_value_matches<'a>(&self, prop: &'a JsonValue) -> Option<&'a JsonValue> {
+        match &self.value {
+            Some(prop_value_matcher) => {
+                Some(prop).filter(|prop| value_satisfies(prop, prop_value_matcher))
             }
-            (_, _) => None,
+            None => Some(prop),
         }
     }
 }
 
 impl SelectionLens for Prop {
     fn select<'a>(&self, input: Option<&'a JsonValue>) -> Option<&'a JsonValue> {
-        match input {
-            Some(JsonValue::Object(ref object)) => match (object.get(&self.name), &self.value) {
-                (Some(prop), Some(JsonValueMemberMatcher::Exact(prop_value_matcher))) => {
-                    Prop::prop_value_matches_exact(prop, prop_value_matcher)
-                }
-                (Some(prop), Some(JsonValueMemberMatcher::ContainsExact(prop_value_matcher))) => {
-                    Prop::prop_value_contains_exact(prop, prop_value_matcher)
-                }
-                (Some(prop), Some(JsonValueMemberMatcher::Prefixed(prop_value_matcher))) => {
-                    Prop::prop_value_is_prefixed_by(prop, prop_value_matcher)
-                }
-                (Some(prop), Some(JsonValueMemberMatcher::Suffixed(prop_value_matcher))) => {
-                    Prop::prop_value_is_suffixed_by(prop, prop_value_matcher)
-                }
-                (Some(prop), Some(JsonValueMemberMatcher::Contains(prop_value_matcher))) => {
-                    Prop::prop_value_contains(prop, prop_value_matcher)
-                }
-                (Some(prop), None) => Some(prop),
-                (None, _) => None,
-            },
+        match (input, &self.name) {
+            (Some(JsonValue::Object(ref object)), PropName::Exact(name)) => object
+                .get(name)
+                .and_then(|prop| self.prop_value_matches(prop)),
+            (Some(JsonValue::Object(_)), _) => self.select_all(input).into_iter().next(),
             _ => None,
         }
     }
-}
 
-pub struct PropParser;
-impl PropParser {
-    fn match_prop(pattern: &str) -> Option<(&str, Option<JsonValueMemberMatcher>, Option<&str>)> {
-        lazy_static! {
-            static ref RE_PROP: Regex =
-                Regex::new(r#"^\.(?P<prop>([[:word:]])+)(?P<remainder>.+)?$"#).unwrap();
-            static ref RE_PROP_VALUE: Regex = Regex::new(
-                concat!(r#"^\{"(?P<prop>([[:word:]])+)"("#,r#"(?P<matchingStrategy>(:|~:|\$:|\^:|\*:)+)"#,r#"("(?P<stringValue>([^"])+)"|(?P<numberValue>([[:digit:]]+)+)|(?P<literalValue>([[:word:]])+)))?\}(?P<remainder>.+)?$"#)
-            )
-            .unwrap();
-        }
-
-        match RE_PROP
-            .captures(pattern)
-            .or(RE_PROP_VALUE.captures(pattern))
-        {
-            Some(cap) => cap
-                .name("prop")
-                .and_then(|prop| match identify_value_matcher(&cap) {
-                    Ok(json_matcher) => Some((
-                        prop.as_str(),
-                        json_matcher,
-                        cap.name("remainder").map(|remainder| remainder.as_str()),
-                    )),
-                    Err(_) => None,
-                }),
-            None => None,
-        }
-    }
-}
-impl SelectionLensParser for PropParser {
-    fn try_parse<'a>(
-        &self,
-        lens_pattern: Option<&'a str>,
-    ) -> Result<(Box<dyn SelectionLens>, Option<&'a str>), Option<&'a str>> {
-        match lens_pattern {
-            Some(pattern) => match PropParser::match_prop(pattern) {
-                Some((prop_name, prop_value, remainder)) => Ok((
-                    Box::new(Prop {
-                        name: String::from(prop_name),
-                        value: prop_value,
-                    }),
-                    remainder,
-                )),
-                None => Err(lens_pattern),
-            },
-            None => Err(lens_pattern),
+    fn select_all<'a>(&self, input: Option<&'a JsonValue>) -> Vec<&'a JsonValue> {
+        match (input, &self.name) {
+            (Some(JsonValue::Object(ref object)), PropName::Glob(_))
+            | (Some(JsonValue::Object(ref object)), PropName::Folded(_, _)) => object
+                .iter()
+                .filter(|(name, _)| self.name.matches(name))
+                .filter_map(|(_, prop)| self.prop_value_matches(prop))
+                .collect(),
+            _ => self.select(input).into_iter().collect(),
         }
     }
 }
@@ -182,12 +101,13 @@ impl SelectionLensParser for PropParser {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::selection::match_filters;
+    use json::array;
     use json::object;
 
     #[test]
     fn should_match_prop() {
-        let prop_parser = PropParser {};
-        let res = prop_parser.try_parse(Some(".name"));
+        let res = match_filters(".name");
         assert!(res.is_ok());
 
         let data = &object! {
@@ -196,30 +116,61 @@ mod tests {
         };
 
         match res {
-            Ok((matcher, _)) => assert_eq!(matcher.select(Some(data)), Some(&data["name"])),
+            Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), Some(&data["name"])),
+            _ => panic!("Invalid result"),
+        }
+    }
+
+    #[test]
+    fn should_match_identity_rather_than_prop() {
+        let data = &object! {
+            "name"    => "John Doe"
+        };
+
+        match match_filters(".") {
+            Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), Some(data)),
             _ => panic!("Invalid result"),
         }
     }
 
     #[test]
-    fn shouldnt_match_identity() {
-        let prop_parser = PropParser {};
-        let res = prop_parser.try_parse(Some("."));
-        assert!(res.is_err());
+    fn should_match_chained_props() {
+        let res = match_filters(".father.title");
+        assert!(res.is_ok());
+
+        let data = &object! {
+            "father"    => object! { "title" => "Mr" }
+        };
+
         match res {
-            Err(Some(selector)) => assert_eq!(selector, "."),
+            Ok(matchers) => {
+                assert_eq!(matchers.len(), 2);
+                assert_eq!(
+                    matchers[1].select(matchers[0].select(Some(data))),
+                    Some(&data["father"]["title"])
+                );
+            }
             _ => panic!("Invalid result"),
         }
     }
 
     #[test]
-    fn should_return_remainder_when_it_matches_prop() {
-        let prop_parser = PropParser {};
-        let res = prop_parser.try_parse(Some(".father.title"));
+    fn should_match_chained_numeric_props() {
+        let res = match_filters(".0.1");
         assert!(res.is_ok());
 
+        let data = &object! {
+            "0"    => object! { "1" => "one" }
+        };
+
         match res {
-            Ok((_, umatched)) => assert_eq!(umatched, Some(".title")),
+            Ok(matchers) => {
+                assert_eq!(matchers.len(), 2);
+                assert_eq!(
+                    matchers[1].select(matchers[0].select(Some(data))),
+                    Some(&data["0"]["1"])
+                );
+            }
             _ => panic!("Invalid result"),
         }
     }
@@ -227,7 +178,7 @@ mod tests {
     #[test]
     fn should_return_none_when_json_isnt_present() {
         let prop = Prop {
-            name: String::from(".id"),
+            name: PropName::Exact(String::from(".id")),
             value: None,
         };
         assert_eq!(prop.select(None), None);
@@ -241,7 +192,7 @@ mod tests {
         };
 
         let prop = Prop {
-            name: String::from("name"),
+            name: PropName::Exact(String::from("name")),
             value: None,
         };
 
@@ -250,8 +201,7 @@ mod tests {
 
     #[test]
     fn should_match_number_prop() {
-        let prop_parser = PropParser {};
-        let res = prop_parser.try_parse(Some(r#"{"age":30}"#));
+        let res = match_filters(r#"{"age":30}"#);
         assert!(res.is_ok());
 
         let data = &object! {
@@ -260,15 +210,14 @@ mod tests {
         };
 
         match res {
-            Ok((matcher, _)) => assert_eq!(matcher.select(Some(data)), Some(&data["age"])),
+            Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), Some(&data["age"])),
             _ => panic!("Invalid result"),
         }
     }
 
     #[test]
     fn should_match_string_property_value_when_using_exact_matching_strategy() {
-        let prop_parser = PropParser {};
-        let res = prop_parser.try_parse(Some(r#"{"country":"IRL"}"#));
+        let res = match_filters(r#"{"country":"IRL"}"#);
         assert!(res.is_ok());
 
         let data = &object! {
@@ -278,15 +227,14 @@ mod tests {
         };
 
         match res {
-            Ok((matcher, _)) => assert_eq!(matcher.select(Some(data)), Some(&data["country"])),
+            Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), Some(&data["country"])),
             _ => panic!("Invalid result"),
         }
     }
 
     #[test]
     fn should_match_string_property_value_when_using_contains_exact_matching_strategy() {
-        let prop_parser = PropParser {};
-        let res = prop_parser.try_parse(Some(r#"{"country"~:"GBR"}"#));
+        let res = match_filters(r#"{"country"~:"GBR"}"#);
         assert!(res.is_ok());
 
         let data = &object! {
@@ -296,15 +244,14 @@ mod tests {
         };
 
         match res {
-            Ok((matcher, _)) => assert_eq!(matcher.select(Some(data)), Some(&data["country"])),
+            Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), Some(&data["country"])),
             _ => panic!("Invalid result"),
         }
     }
 
     #[test]
     fn should_match_string_property_value_when_using_prefixed_matching_strategy() {
-        let prop_parser = PropParser {};
-        let res = prop_parser.try_parse(Some(r#"{"country"^:"IRL"}"#));
+        let res = match_filters(r#"{"country"^:"IRL"}"#);
         assert!(res.is_ok());
 
         let data = &object! {
@@ -314,15 +261,14 @@ mod tests {
         };
 
         match res {
-            Ok((matcher, _)) => assert_eq!(matcher.select(Some(data)), Some(&data["country"])),
+            Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), Some(&data["country"])),
             _ => panic!("Invalid result"),
         }
     }
 
     #[test]
     fn should_match_string_property_value_when_using_suffixed_matching_strategy() {
-        let prop_parser = PropParser {};
-        let res = prop_parser.try_parse(Some(r#"{"country"$:"GBR"}"#));
+        let res = match_filters(r#"{"country"$:"GBR"}"#);
         assert!(res.is_ok());
 
         let data = &object! {
@@ -332,15 +278,14 @@ mod tests {
         };
 
         match res {
-            Ok((matcher, _)) => assert_eq!(matcher.select(Some(data)), Some(&data["country"])),
+            Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), Some(&data["country"])),
             _ => panic!("Invalid result"),
         }
     }
 
     #[test]
     fn should_match_boolean_false_prop() {
-        let prop_parser = PropParser {};
-        let res = prop_parser.try_parse(Some(r#"{"is_known":false}"#));
+        let res = match_filters(r#"{"is_known":false}"#);
         assert!(res.is_ok());
 
         let data = &object! {
@@ -350,15 +295,14 @@ mod tests {
         };
 
         match res {
-            Ok((matcher, _)) => assert_eq!(matcher.select(Some(data)), Some(&data["is_known"])),
+            Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), Some(&data["is_known"])),
             _ => panic!("Invalid result"),
         }
     }
 
     #[test]
     fn should_match_boolean_true_prop() {
-        let prop_parser = PropParser {};
-        let res = prop_parser.try_parse(Some(r#"{"is_anonymous":true}"#));
+        let res = match_filters(r#"{"is_anonymous":true}"#);
         assert!(res.is_ok());
 
         let data = &object! {
@@ -368,15 +312,14 @@ mod tests {
         };
 
         match res {
-            Ok((matcher, _)) => assert_eq!(matcher.select(Some(data)), Some(&data["is_anonymous"])),
+            Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), Some(&data["is_anonymous"])),
             _ => panic!("Invalid result"),
         }
     }
 
     #[test]
     fn should_match_null_prop() {
-        let prop_parser = PropParser {};
-        let res = prop_parser.try_parse(Some(r#"{"identity":null}"#));
+        let res = match_filters(r#"{"identity":null}"#);
         assert!(res.is_ok());
 
         let data = &object! {
@@ -386,7 +329,395 @@ mod tests {
         };
 
         match res {
-            Ok((matcher, _)) => assert_eq!(matcher.select(Some(data)), Some(&data["identity"])),
+            Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), Some(&data["identity"])),
+            _ => panic!("Invalid result"),
+        }
+    }
+
+    #[test]
+    fn should_match_number_property_value_when_using_comparison_matching_strategies() {
+        let data = &object! {
+            "name"          => "John Doe",
+            "latency_ms"    => 750
+        };
+
+        for pattern in &[
+            r#"{"latency_ms">:500}"#,
+            r#"{"latency_ms">=:750}"#,
+            r#"{"latency_ms"<:1000}"#,
+            r#"{"latency_ms"<=:750}"#,
+            r#"{"latency_ms"!:500}"#,
+        ] {
+            match match_filters(pattern) {
+                Ok(matchers) => {
+                    assert_eq!(matchers[0].select(Some(data)), Some(&data["latency_ms"]))
+                }
+                _ => panic!("Invalid result"),
+            }
+        }
+
+        for pattern in &[
+            r#"{"latency_ms">:750}"#,
+            r#"{"latency_ms"<:750}"#,
+            r#"{"latency_ms"!:750}"#,
+            r#"{"name">:500}"#,
+        ] {
+            match match_filters(pattern) {
+                Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), None),
+                _ => panic!("Invalid result"),
+            }
+        }
+    }
+
+    #[test]
+    fn should_match_number_property_value_within_range() {
+        let data = &object! {
+            "name"      => "John Doe",
+            "status"    => 599
+        };
+
+        for pattern in &[
+            r#"{"status":500..599}"#,
+            r#"{"status":500..=599}"#,
+            r#"{"status":500..<600}"#,
+            r#"{"status":599..}"#,
+            r#"{"status":..599}"#,
+        ] {
+            match match_filters(pattern) {
+                Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), Some(&data["status"])),
+                _ => panic!("Invalid result"),
+            }
+        }
+
+        for pattern in &[
+            r#"{"status":500..<599}"#,
+            r#"{"status":600..}"#,
+            r#"{"name":..600}"#,
+        ] {
+            match match_filters(pattern) {
+                Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), None),
+                _ => panic!("Invalid result"),
+            }
+        }
+    }
+
+    #[test]
+    fn shouldnt_match_range_without_bounds_or_with_non_exact_matching_strategy() {
+        assert!(match_filters(r#"{"status":..}"#).is_err());
+        assert!(match_filters(r#"{"status"^:1..5}"#).is_err());
+    }
+
+    #[test]
+    fn should_match_negative_and_fractional_number_prop() {
+        let data = &object! {
+            "temp"      => -3,
+            "score"     => 0.75,
+            "ratio"     => 1
+        };
+
+        for (pattern, prop) in &[
+            (r#"{"temp":-3}"#, "temp"),
+            (r#"{"score":0.75}"#, "score"),
+            (r#"{"score":7.5e-1}"#, "score"),
+            (r#"{"ratio":1.0}"#, "ratio"),
+            (r#"{"ratio":1e0}"#, "ratio"),
+            (r#"{"temp":-5..0}"#, "temp"),
+            (r#"{"score"<:0.8}"#, "score"),
+        ] {
+            match match_filters(pattern) {
+                Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), Some(&data[*prop])),
+                _ => panic!("Invalid result"),
+            }
+        }
+    }
+
+    #[test]
+    fn shouldnt_match_invalid_json_numbers() {
+        assert!(match_filters(r#"{"temp":-}"#).is_err());
+        assert!(match_filters(r#"{"temp":01}"#).is_err());
+        assert!(match_filters(r#"{"temp":1.}"#).is_err());
+    }
+
+    #[test]
+    fn should_match_string_property_value_when_using_regex_matching_strategy() {
+        let data = &object! {
+            "msg"       => "request timeout after 500ms",
+            "path"      => "/api/v2/users"
+        };
+
+        match match_filters(r#"{"msg"~/timeout after \d+ms/}"#) {
+            Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), Some(&data["msg"])),
+            _ => panic!("Invalid result"),
+        }
+
+        match match_filters(r#"{"path"~/^\/api\/v\d+\//}"#) {
+            Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), Some(&data["path"])),
+            _ => panic!("Invalid result"),
+        }
+
+        match match_filters(r#"{"msg"~/^timeout/}"#) {
+            Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), None),
+            _ => panic!("Invalid result"),
+        }
+    }
+
+    #[test]
+    fn shouldnt_match_invalid_regex() {
+        assert!(match_filters(r#"{"msg"~/(unclosed/}"#).is_err());
+        assert!(match_filters(r#"{"msg":/timeout/}"#).is_err());
+    }
+
+    #[test]
+    fn should_match_quoted_prop_names() {
+        let data = &object! {
+            "content-type"  => "application/json",
+            "@timestamp"    => "2019-03-01T00:00:00Z",
+            "user.id"       => 42,
+            "$ref"          => "#/definitions/user",
+            "naïve"         => true,
+            "say \"hi\""    => "hi"
+        };
+
+        for (pattern, prop) in &[
+            (r#"."content-type""#, "content-type"),
+            (r#".["@timestamp"]"#, "@timestamp"),
+            (r#"."user.id""#, "user.id"),
+            (r#".["$ref"]"#, "$ref"),
+            (r#"."naïve""#, "naïve"),
+            (r#"."say \"hi\"""#, "say \"hi\""),
+            (r#"{"content-type":"application/json"}"#, "content-type"),
+            (r#"{"user.id":42}"#, "user.id"),
+            (r#"{"naïve":true}"#, "naïve"),
+        ] {
+            match match_filters(pattern) {
+                Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), Some(&data[*prop])),
+                _ => panic!("Invalid result"),
+            }
+        }
+    }
+
+    #[test]
+    fn should_match_chained_quoted_props() {
+        let data = &object! {
+            "http.request"  => object! { "content-type" => "application/json" }
+        };
+
+        match match_filters(r#"."http.request".["content-type"]"#) {
+            Ok(matchers) => assert_eq!(
+                matchers[1].select(matchers[0].select(Some(data))),
+                Some(&data["http.request"]["content-type"])
+            ),
+            _ => panic!("Invalid result"),
+        }
+    }
+
+    #[test]
+    fn should_match_string_property_values_with_escapes() {
+        let data = &object! {
+            "error"     => "",
+            "query"     => "say \"hi\"",
+            "trace"     => "line 1\nline 2",
+            "city"      => "Zürich"
+        };
+
+        for (pattern, prop) in &[
+            (r#"{"error":""}"#, "error"),
+            (r#"{"query":"say \"hi\""}"#, "query"),
+            (r#"{"query"$:"\"hi\""}"#, "query"),
+            (r#"{"trace"*:"1\nline"}"#, "trace"),
+            (r#"{"city":"Zürich"}"#, "city"),
+        ] {
+            match match_filters(pattern) {
+                Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), Some(&data[*prop])),
+                _ => panic!("Invalid result"),
+            }
+        }
+    }
+
+    #[test]
+    fn shouldnt_match_invalid_string_escapes() {
+        assert!(match_filters(r#"{"query":"\q"}"#).is_err());
+        assert!(match_filters(r#"{"query":"\u12"}"#).is_err());
+    }
+
+    #[test]
+    fn should_select_every_prop_matching_glob() {
+        let data = &object! {
+            "request_id" => "abc",
+            "name" => "John Doe",
+            "user_id" => "def"
+        };
+
+        match match_filters(".*_id") {
+            Ok(matchers) => {
+                assert_eq!(matchers[0].select(Some(data)), Some(&data["request_id"]));
+                assert_eq!(
+                    matchers[0].select_all(Some(data)),
+                    vec![&data["request_id"], &data["user_id"]]
+                );
+            }
+            _ => panic!("Invalid result"),
+        }
+
+        match match_filters(".*") {
+            Ok(matchers) => assert_eq!(matchers[0].select_all(Some(data)).len(), 3),
+            _ => panic!("Invalid result"),
+        }
+    }
+
+    #[test]
+    fn should_match_prop_value_under_any_key_matching_glob() {
+        let data = &object! {
+            "request_id" => "abc",
+            "user_id" => "def"
+        };
+
+        match match_filters(r#"{"*_id":"def"}"#) {
+            Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), Some(&data["user_id"])),
+            _ => panic!("Invalid result"),
+        }
+
+        match match_filters(r#"{"*_id":"xyz"}"#) {
+            Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), None),
+            _ => panic!("Invalid result"),
+        }
+    }
+
+    #[test]
+    fn should_match_literal_star_when_quoted_or_escaped() {
+        let data = &object! {
+            "*" => "star",
+            "*_id" => "abc",
+            "name" => "John Doe"
+        };
+
+        match match_filters(r#"."*""#) {
+            Ok(matchers) => assert_eq!(matchers[0].select_all(Some(data)), vec![&data["*"]]),
+            _ => panic!("Invalid result"),
+        }
+
+        match match_filters(r#"{"\\*_id":"abc"}"#) {
+            Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), Some(&data["*_id"])),
+            _ => panic!("Invalid result"),
+        }
+
+        match match_filters(r#"{"\\*":"abc"}"#) {
+            Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), None),
+            _ => panic!("Invalid result"),
+        }
+    }
+
+    #[test]
+    fn should_match_object_value_containing_the_specified_props() {
+        let data = &object! {
+            "user" => object! {
+                "name" => "jeff goldblum",
+                "role" => "admin",
+                "address" => object! { "city" => "Pittsburgh", "zip" => "15201" }
+            }
+        };
+
+        for pattern in &[
+            r#"{"user":{"role":"admin"}}"#,
+            r#"{"user":{}}"#,
+            r#"{"user":{"address":{"city":"Pittsburgh"},"role":"admin"}}"#,
+        ] {
+            match match_filters(pattern) {
+                Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), Some(&data["user"])),
+                _ => panic!("Invalid result"),
+            }
+        }
+
+        for pattern in &[
+            r#"{"user":{"role":"guest"}}"#,
+            r#"{"user":{"email":null}}"#,
+            r#"{"user":[]}"#,
+        ] {
+            match match_filters(pattern) {
+                Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), None),
+                _ => panic!("Invalid result"),
+            }
+        }
+    }
+
+    #[test]
+    fn should_match_array_value_with_exactly_the_specified_members() {
+        let data = &object! {
+            "tags" => array!["a", "b"]
+        };
+
+        match match_filters(r#"{"tags":["a","b"]}"#) {
+            Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), Some(&data["tags"])),
+            _ => panic!("Invalid result"),
+        }
+
+        for pattern in &[
+            r#"{"tags":["a"]}"#,
+            r#"{"tags":["b","a"]}"#,
+            r#"{"tags":["a","b","c"]}"#,
+        ] {
+            match match_filters(pattern) {
+                Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), None),
+                _ => panic!("Invalid result"),
+            }
+        }
+    }
+
+    #[test]
+    fn should_ignore_case_of_values_and_names_followed_by_the_i_flag() {
+        let data = &object! {
+            "Name" => "JEFF Goldblum",
+            "nickname" => "Jeff"
+        };
+
+        match match_filters(r#"{"Name":"jeff goldblum"i}"#) {
+            Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), Some(&data["Name"])),
+            _ => panic!("Invalid result"),
+        }
+
+        match match_filters(r#"{"name":"jeff goldblum"i}"#) {
+            Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), None),
+            _ => panic!("Invalid result"),
+        }
+
+        match match_filters(r#"{"*NAME"i^:"jeff"i}"#) {
+            Ok(matchers) => assert_eq!(
+                matchers[0].select_all(Some(data)),
+                vec![&data["Name"], &data["nickname"]]
+            ),
+            _ => panic!("Invalid result"),
+        }
+
+        match match_filters(r#"{"nickname"~/^JEFF$/i}"#) {
+            Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), Some(&data["nickname"])),
+            _ => panic!("Invalid result"),
+        }
+    }
+
+    #[test]
+    fn should_compare_values_followed_by_normalization_flags_after_normalizing() {
+        let data = &object! {
+            "city" => "Zu\u{308}rich",
+            "unit" => "ﬁfth ﬂoor"
+        };
+
+        match match_filters(r#"{"city":"Zürich"}"#) {
+            Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), None),
+            _ => panic!("Invalid result"),
+        }
+
+        match match_filters(r#"{"city":"Zürich"n}"#) {
+            Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), Some(&data["city"])),
+            _ => panic!("Invalid result"),
+        }
+
+        match match_filters(r#"{"city"^:"ZUR"ia}"#) {
+            Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), Some(&data["city"])),
+            _ => panic!("Invalid result"),
+        }
+
+        match match_filters(r#"{"unit"~:"floor"k}"#) {
+            Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), Some(&data["unit"])),
             _ => panic!("Invalid result"),
         }
     }
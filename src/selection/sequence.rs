@@ -1,51 +1,62 @@
 use json::JsonValue;
-use regex::Regex;
 
-use super::{match_json_slice, try_to_match_filters, SelectionLens, SelectionLensParser};
+use super::value_matchers::{count_satisfies, JsonValueMemberMatcher};
+use super::{match_json_slice, SelectionLens};
 
-struct Sequence {
-    matchers: Vec<Box<dyn SelectionLens>>,
+fn matching_members<'a>(
+    matchers: &Vec<Box<dyn SelectionLens>>,
+    array: &'a [JsonValue],
+) -> Vec<&'a JsonValue> {
+    array
+        .iter()
+        .filter(|member| match_json_slice(matchers, member, true).is_ok())
+        .collect()
+}
+
+pub struct Sequence {
+    pub matchers: Vec<Box<dyn SelectionLens>>,
 }
 
 impl SelectionLens for Sequence {
     fn select<'a>(&self, input: Option<&'a JsonValue>) -> Option<&'a JsonValue> {
+        self.select_all(input).into_iter().next()
+    }
+
+    fn select_all<'a>(&self, input: Option<&'a JsonValue>) -> Vec<&'a JsonValue> {
         match input {
-            Some(json) => match json {
-                JsonValue::Array(ref array) => array
-                    .iter()
-                    .find(|member| match_json_slice(&self.matchers, member, true).is_ok()),
-                _ => None,
-            },
-            None => None,
+            Some(JsonValue::Array(ref array)) => matching_members(&self.matchers, array),
+            _ => vec![],
         }
     }
 }
 
-pub struct SequenceParser;
-impl SequenceParser {
-    fn match_sequence(pattern: &str) -> Option<&str> {
-        lazy_static! {
-            static ref RE_SEQUENCE: Regex =
-                Regex::new(r#"^\[(?P<sequence_matcher>(.)+)\]$"#).unwrap();
-        }
+pub enum Quantifier {
+    All,
+    None,
+    Count(JsonValueMemberMatcher),
+}
 
-        RE_SEQUENCE
-            .captures(pattern)
-            .and_then(|cap| cap.name("sequence_matcher"))
-            .map(|sequence_matcher| sequence_matcher.as_str())
-    }
+/// Unlike a plain sequence, a quantified sequence selects the array itself, as its match
+/// depends on every member rather than on any single one of them.
+pub struct QuantifiedSequence {
+    pub quantifier: Quantifier,
+    pub matchers: Vec<Box<dyn SelectionLens>>,
 }
-impl SelectionLensParser for SequenceParser {
-    fn try_parse<'a>(
-        &self,
-        lens_pattern: Option<&'a str>,
-    ) -> Result<(Box<dyn SelectionLens>, Option<&'a str>), Option<&'a str>> {
-        match lens_pattern
-            .and_then(SequenceParser::match_sequence)
-            .map(try_to_match_filters)
-        {
-            Some(Ok(matchers)) => Ok((Box::new(Sequence { matchers }), None)),
-            _ => Err(lens_pattern),
+
+impl SelectionLens for QuantifiedSequence {
+    fn select<'a>(&self, input: Option<&'a JsonValue>) -> Option<&'a JsonValue> {
+        match input {
+            Some(JsonValue::Array(ref array)) => {
+                let matching_count = matching_members(&self.matchers, array).len();
+                input.filter(|_| match &self.quantifier {
+                    Quantifier::All => matching_count == array.len(),
+                    Quantifier::None => matching_count == 0,
+                    Quantifier::Count(count_matcher) => {
+                        count_satisfies(matching_count, count_matcher)
+                    }
+                })
+            }
+            _ => None,
         }
     }
 }
@@ -53,13 +64,13 @@ impl SelectionLensParser for SequenceParser {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::selection::match_filters;
     use json::array;
     use json::object;
 
     #[test]
     fn should_match_json_in_sequence_when_matching_query() {
-        let sequence_parser = SequenceParser {};
-        let res = sequence_parser.try_parse(Some("[.name]"));
+        let res = match_filters("[.name]");
         assert!(res.is_ok());
 
         let data = &object! {
@@ -71,14 +82,73 @@ mod tests {
         };
 
         match res {
-            Ok((matcher, _)) => assert_eq!(
-                matcher.select(Some(&data["identities"])),
+            Ok(matchers) => assert_eq!(
+                matchers[0].select(Some(&data["identities"])),
                 Some(&data["identities"][0])
             ),
             _ => panic!("Invalid result"),
         }
     }
 
+    #[test]
+    fn should_select_array_when_every_member_matches() {
+        let data = &array![object! { "ok" => true }, object! { "ok" => false }];
+
+        match match_filters("[all: .ok]") {
+            Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), Some(data)),
+            _ => panic!("Invalid result"),
+        }
+
+        match match_filters(r#"[all: {"ok":true}]"#) {
+            Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), None),
+            _ => panic!("Invalid result"),
+        }
+    }
+
+    #[test]
+    fn should_select_array_when_no_member_matches() {
+        let data = &array![
+            object! { "status" => "done" },
+            object! { "status" => "queued" }
+        ];
+
+        match match_filters(r#"[none: {"status":"failed"}]"#) {
+            Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), Some(data)),
+            _ => panic!("Invalid result"),
+        }
+
+        match match_filters(r#"[none: {"status":"done"}]"#) {
+            Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), None),
+            _ => panic!("Invalid result"),
+        }
+    }
+
+    #[test]
+    fn should_select_array_when_count_of_matching_members_satisfies_comparison() {
+        let data = &array![
+            object! { "name" => "Oleg Kononenko" },
+            object! { "craft" => "ISS" },
+            object! { "name" => "Anne McClain" }
+        ];
+
+        for pattern in &[
+            "[#=2: .name]",
+            "[#>=2: .name]",
+            "[#<3: .name]",
+            "[#=1..=2: .name]",
+        ] {
+            match match_filters(pattern) {
+                Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), Some(data)),
+                _ => panic!("Invalid result"),
+            }
+        }
+
+        match match_filters("[#>=3: .name]") {
+            Ok(matchers) => assert_eq!(matchers[0].select(Some(data)), None),
+            _ => panic!("Invalid result"),
+        }
+    }
+
     #[test]
     fn should_return_none_when_json_sequence_is_empty() {
         let data = &object! {
@@ -88,9 +158,25 @@ mod tests {
         };
 
         let sequence = Sequence {
-            matchers: try_to_match_filters(".").unwrap(),
+            matchers: match_filters(".").unwrap(),
         };
 
         assert_eq!(sequence.select(Some(&data["identities"])), None);
     }
+
+    #[test]
+    fn should_select_every_member_matching_query() {
+        let data = &array![
+            object! { "name" => "Oleg Kononenko" },
+            object! { "craft" => "ISS" },
+            object! { "name" => "Anne McClain" }
+        ];
+
+        match match_filters("[.name]") {
+            Ok(matchers) => {
+                assert_eq!(matchers[0].select_all(Some(data)), vec![&data[0], &data[2]])
+            }
+            _ => panic!("Invalid result"),
+        }
+    }
 }
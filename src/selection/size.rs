@@ -0,0 +1,81 @@
+use json::JsonValue;
+
+use super::value_matchers::{count_satisfies, JsonValueMemberMatcher};
+use super::SelectionLens;
+
+pub struct Size {
+    pub count: JsonValueMemberMatcher,
+}
+
+impl SelectionLens for Size {
+    fn select<'a>(&self, input: Option<&'a JsonValue>) -> Option<&'a JsonValue> {
+        let size = match input {
+            Some(JsonValue::Array(ref array)) => array.len(),
+            Some(JsonValue::Object(ref object)) => object.len(),
+            _ => return None,
+        };
+        input.filter(|_| count_satisfies(size, &self.count))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::selection::match_filters;
+    use crate::selection::value_matchers::JsonValueMatcher;
+    use json::array;
+    use json::object;
+
+    #[test]
+    fn should_match_array_length() {
+        let data = &object! {
+            "items" => array![],
+            "tags" => array!["a", "b", "c"]
+        };
+
+        match match_filters(".items#=0") {
+            Ok(matchers) => assert_eq!(
+                matchers[1].select(matchers[0].select(Some(data))),
+                Some(&data["items"])
+            ),
+            _ => panic!("Invalid result"),
+        }
+
+        match match_filters(".tags#>3") {
+            Ok(matchers) => assert_eq!(matchers[1].select(matchers[0].select(Some(data))), None),
+            _ => panic!("Invalid result"),
+        }
+    }
+
+    #[test]
+    fn should_match_object_key_count() {
+        let data = &object! {
+            "headers" => object! {
+                "host" => "example.com",
+                "accept" => "*/*"
+            }
+        };
+
+        match match_filters(".headers#<=2") {
+            Ok(matchers) => assert_eq!(
+                matchers[1].select(matchers[0].select(Some(data))),
+                Some(&data["headers"])
+            ),
+            _ => panic!("Invalid result"),
+        }
+
+        match match_filters(".headers#!=2") {
+            Ok(matchers) => assert_eq!(matchers[1].select(matchers[0].select(Some(data))), None),
+            _ => panic!("Invalid result"),
+        }
+    }
+
+    #[test]
+    fn shouldnt_match_values_which_arent_collections() {
+        let size = Size {
+            count: JsonValueMemberMatcher::GreaterThanOrEqual(JsonValueMatcher::Number(0.into())),
+        };
+        assert_eq!(size.select(Some(&"abc".into())), None);
+        assert_eq!(size.select(None), None);
+    }
+}
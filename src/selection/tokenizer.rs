@@ -0,0 +1,420 @@
+use json::number::Number;
+use json::JsonValue;
+use regex::Regex;
+
+use super::parser::ParseError;
+use super::value_matchers::parse_json_string;
+
+#[derive(Debug, PartialEq)]
+pub enum Token {
+    Anchor,
+    Dot,
+    DotDot,
+    LeftBracket,
+    RightBracket,
+    LeftBrace,
+    RightBrace,
+    LeftParen,
+    RightParen,
+    Pipe,
+    Comma,
+    Hash,
+    Operator(String),
+    String(String),
+    Number(Number),
+    Word(String),
+    Regex(String),
+    ValuesFile(String),
+}
+
+impl Token {
+    pub fn describe(&self) -> String {
+        match self {
+            Token::Anchor => String::from("\"^\""),
+            Token::Dot => String::from("\".\""),
+            Token::DotDot => String::from("\"..\""),
+            Token::LeftBracket => String::from("\"[\""),
+            Token::RightBracket => String::from("\"]\""),
+            Token::LeftBrace => String::from("\"{\""),
+            Token::RightBrace => String::from("\"}\""),
+            Token::LeftParen => String::from("\"(\""),
+            Token::RightParen => String::from("\")\""),
+            Token::Pipe => String::from("\"|\""),
+            Token::Comma => String::from("\",\""),
+            Token::Hash => String::from("\"#\""),
+            Token::Operator(operator) => format!("{:?}", operator),
+            Token::String(_) => String::from("a string"),
+            Token::Number(_) => String::from("a number"),
+            Token::Word(word) => format!("{:?}", word),
+            Token::Regex(_) => String::from("a regular expression"),
+            Token::ValuesFile(_) => String::from("a values file"),
+        }
+    }
+}
+
+pub struct SpannedToken<'a> {
+    pub token: Token,
+    pub position: usize,
+    pub text: &'a str,
+}
+
+const OPERATOR_CHARACTERS: &str = "~^$*!<>=:";
+
+fn is_word_character(character: char) -> bool {
+    character.is_alphanumeric() || character == '_'
+}
+
+fn find_closing_delimiter(pattern: &str, start: usize, delimiter: char) -> Option<usize> {
+    let mut is_escaped = false;
+    pattern[start + 1..]
+        .char_indices()
+        .find(|&(_, character)| match character {
+            _ if is_escaped => {
+                is_escaped = false;
+                false
+            }
+            '\\' => {
+                is_escaped = true;
+                false
+            }
+            character => character == delimiter,
+        })
+        .map(|(index, _)| start + 1 + index)
+}
+
+fn number_length(pattern: &str) -> usize {
+    lazy_static! {
+        static ref RE_NUMBER: Regex =
+            Regex::new(r#"^-?(0|[1-9][[:digit:]]*)(\.[[:digit:]]+)?([eE][+-]?[[:digit:]]+)?"#)
+                .unwrap();
+    }
+
+    RE_NUMBER
+        .find(pattern)
+        .map(|number| number.end())
+        .unwrap_or(0)
+}
+
+fn word_length(pattern: &str, allows_glob: bool) -> usize {
+    pattern
+        .char_indices()
+        .find(|&(_, character)| {
+            !(is_word_character(character) || (allows_glob && character == '*'))
+        })
+        .map(|(index, _)| index)
+        .unwrap_or_else(|| pattern.len())
+}
+
+pub fn tokenize(pattern: &str) -> Result<Vec<SpannedToken<'_>>, ParseError> {
+    let mut tokens = vec![];
+    let mut position = 0;
+
+    while let Some(character) = pattern[position..].chars().next() {
+        let rest = &pattern[position..];
+        let follows_dot = matches!(
+            tokens.last(),
+            Some(SpannedToken {
+                token: Token::Dot,
+                text,
+                position: dot_position,
+            }) | Some(SpannedToken {
+                token: Token::DotDot,
+                text,
+                position: dot_position,
+            }) if dot_position + text.len() == position
+        );
+        let follows_single_dot = follows_dot
+            && matches!(
+                tokens.last(),
+                Some(SpannedToken {
+                    token: Token::Dot,
+                    ..
+                })
+            );
+        let (token, length) = match character {
+            _ if character.is_whitespace() => {
+                position += character.len_utf8();
+                continue;
+            }
+            '^' if tokens.is_empty() => (Token::Anchor, 1),
+            '.' if rest.starts_with("..") => (Token::DotDot, 2),
+            '.' => (Token::Dot, 1),
+            '[' => (Token::LeftBracket, 1),
+            ']' => (Token::RightBracket, 1),
+            '{' => (Token::LeftBrace, 1),
+            '}' => (Token::RightBrace, 1),
+            '(' => (Token::LeftParen, 1),
+            ')' => (Token::RightParen, 1),
+            '|' => (Token::Pipe, 1),
+            ',' => (Token::Comma, 1),
+            '#' => (Token::Hash, 1),
+            '"' => match find_closing_delimiter(pattern, position, '"') {
+                Some(end) => match parse_json_string(&pattern[position..=end]) {
+                    Ok(string_value) => (Token::String(string_value), end + 1 - position),
+                    Err(_) => {
+                        return Err(ParseError::new(position, "a valid JSON string"));
+                    }
+                },
+                None => {
+                    return Err(ParseError::new(
+                        pattern.len(),
+                        "a closing '\"' to end the string",
+                    ));
+                }
+            },
+            '/' => match find_closing_delimiter(pattern, position, '/') {
+                Some(end) => (
+                    Token::Regex(pattern[position + 1..end].replace("\\/", "/")),
+                    end + 1 - position,
+                ),
+                None => {
+                    return Err(ParseError::new(
+                        pattern.len(),
+                        "a closing '/' to end the regular expression",
+                    ));
+                }
+            },
+            '@' if rest[1..].starts_with('"') => {
+                match find_closing_delimiter(pattern, position + 1, '"') {
+                    Some(end) => match parse_json_string(&pattern[position + 1..=end]) {
+                        Ok(path) => (Token::ValuesFile(path), end + 1 - position),
+                        Err(_) => {
+                            return Err(ParseError::new(position + 1, "a valid JSON string"));
+                        }
+                    },
+                    None => {
+                        return Err(ParseError::new(
+                            pattern.len(),
+                            "a closing '\"' to end the string",
+                        ));
+                    }
+                }
+            }
+            '@' => {
+                let length = rest
+                    .char_indices()
+                    .find(|&(_, character)| {
+                        character.is_whitespace() || ",]})|".contains(character)
+                    })
+                    .map(|(index, _)| index)
+                    .unwrap_or_else(|| rest.len());
+                (Token::ValuesFile(String::from(&rest[1..length])), length)
+            }
+            '-' | '0'..='9'
+                if !follows_single_dot && number_length(rest) >= word_length(rest, follows_dot) =>
+            {
+                let length = number_length(rest);
+                match json::parse(&rest[..length]) {
+                    Ok(JsonValue::Number(number)) => (Token::Number(number), length),
+                    _ => return Err(ParseError::new(position, "a valid JSON number")),
+                }
+            }
+            _ if is_word_character(character) || (character == '*' && follows_dot) => {
+                let length = word_length(rest, follows_dot);
+                (Token::Word(String::from(&rest[..length])), length)
+            }
+            _ if OPERATOR_CHARACTERS.contains(character) => {
+                let length = rest
+                    .char_indices()
+                    .find(|&(_, character)| !OPERATOR_CHARACTERS.contains(character))
+                    .map(|(index, _)| index)
+                    .unwrap_or_else(|| rest.len());
+                (Token::Operator(String::from(&rest[..length])), length)
+            }
+            _ => {
+                return Err(ParseError::unexpected(
+                    position,
+                    &format!("{:?}", character),
+                    "a selector",
+                ));
+            }
+        };
+
+        tokens.push(SpannedToken {
+            token,
+            position,
+            text: &rest[..length],
+        });
+        position += length;
+    }
+
+    Ok(tokens)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tokens(pattern: &str) -> Vec<Token> {
+        tokenize(pattern)
+            .unwrap()
+            .into_iter()
+            .map(|spanned| spanned.token)
+            .collect()
+    }
+
+    #[test]
+    fn should_tokenize_props_and_sequences() {
+        assert_eq!(
+            tokens(".people[.name]"),
+            vec![
+                Token::Dot,
+                Token::Word(String::from("people")),
+                Token::LeftBracket,
+                Token::Dot,
+                Token::Word(String::from("name")),
+                Token::RightBracket,
+            ]
+        );
+    }
+
+    #[test]
+    fn should_tokenize_value_matchers() {
+        assert_eq!(
+            tokens(r#"{"status">=:-1.5e2}"#),
+            vec![
+                Token::LeftBrace,
+                Token::String(String::from("status")),
+                Token::Operator(String::from(">=:")),
+                Token::Number((-150).into()),
+                Token::RightBrace,
+            ]
+        );
+        assert_eq!(
+            tokens(r#"[~/^v\d+\/]/]"#),
+            vec![
+                Token::LeftBracket,
+                Token::Operator(String::from("~")),
+                Token::Regex(String::from(r#"^v\d+/]"#)),
+                Token::RightBracket,
+            ]
+        );
+    }
+
+    #[test]
+    fn should_tokenize_ranges() {
+        assert_eq!(
+            tokens("500..=599"),
+            vec![
+                Token::Number(500.into()),
+                Token::DotDot,
+                Token::Operator(String::from("=")),
+                Token::Number(599.into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_tokenize_words_starting_with_digits() {
+        assert_eq!(
+            tokens(".2019_logs.1e5"),
+            vec![
+                Token::Dot,
+                Token::Word(String::from("2019_logs")),
+                Token::Dot,
+                Token::Word(String::from("1e5")),
+            ]
+        );
+        assert_eq!(
+            tokens(".0.1"),
+            vec![
+                Token::Dot,
+                Token::Word(String::from("0")),
+                Token::Dot,
+                Token::Word(String::from("1")),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_tokenize_globs_in_prop_names() {
+        assert_eq!(
+            tokens(".*.http_*[*=\"a\"]"),
+            vec![
+                Token::Dot,
+                Token::Word(String::from("*")),
+                Token::Dot,
+                Token::Word(String::from("http_*")),
+                Token::LeftBracket,
+                Token::Operator(String::from("*=")),
+                Token::String(String::from("a")),
+                Token::RightBracket,
+            ]
+        );
+        assert_eq!(
+            tokens(r#"{"name"i*:"jeff"}"#),
+            vec![
+                Token::LeftBrace,
+                Token::String(String::from("name")),
+                Token::Word(String::from("i")),
+                Token::Operator(String::from("*:")),
+                Token::String(String::from("jeff")),
+                Token::RightBrace,
+            ]
+        );
+    }
+
+    #[test]
+    fn should_tokenize_anchor_only_at_the_start_of_the_pattern() {
+        assert_eq!(
+            tokens(r#" ^..id{"a"^:"b"}"#),
+            vec![
+                Token::Anchor,
+                Token::DotDot,
+                Token::Word(String::from("id")),
+                Token::LeftBrace,
+                Token::String(String::from("a")),
+                Token::Operator(String::from("^:")),
+                Token::String(String::from("b")),
+                Token::RightBrace,
+            ]
+        );
+    }
+
+    #[test]
+    fn should_skip_whitespace_between_tokens() {
+        assert_eq!(
+            tokens(r#"{ "name" : "jeff goldblum" }"#),
+            vec![
+                Token::LeftBrace,
+                Token::String(String::from("name")),
+                Token::Operator(String::from(":")),
+                Token::String(String::from("jeff goldblum")),
+                Token::RightBrace,
+            ]
+        );
+    }
+
+    #[test]
+    fn should_fail_on_unterminated_strings_and_unexpected_characters() {
+        assert_eq!(tokenize(r#"{"name"#).err().map(|err| err.position), Some(6));
+        assert_eq!(tokenize(".name&").err().map(|err| err.position), Some(5));
+    }
+
+    #[test]
+    fn should_tokenize_values_file_references() {
+        assert_eq!(
+            tokens(r#"{"id" in @ids/accounts.txt}[in @"my ids.txt"]"#),
+            vec![
+                Token::LeftBrace,
+                Token::String(String::from("id")),
+                Token::Word(String::from("in")),
+                Token::ValuesFile(String::from("ids/accounts.txt")),
+                Token::RightBrace,
+                Token::LeftBracket,
+                Token::Word(String::from("in")),
+                Token::ValuesFile(String::from("my ids.txt")),
+                Token::RightBracket,
+            ]
+        );
+        assert_eq!(
+            tokens("[in @]"),
+            vec![
+                Token::LeftBracket,
+                Token::Word(String::from("in")),
+                Token::ValuesFile(String::new()),
+                Token::RightBracket,
+            ]
+        );
+    }
+}
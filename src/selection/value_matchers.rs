@@ -1,8 +1,167 @@
+use caseless::default_case_fold_str;
+use json::number::Number;
+use json::JsonValue;
+use regex::Regex;
+use std::cmp::Ordering;
+use std::collections::HashSet;
+
+use super::normalization::{normalize, strip_diacritics, NormalForm};
+use super::prop::PropName;
+
+/// Full Unicode case folding, under which "ß", "ẞ" and "SS" all fold to "ss". Unlike
+/// lower casing, a final "ς" folds to "σ" wherever it appears, so that a prefix or a
+/// substring folds the same way as the string it was taken from.
+pub fn fold_case(value: &str) -> String {
+    default_case_fold_str(value)
+}
+
+/// The differences between strings which a matcher ignores, applied to both the matched
+/// value and the input before they are compared.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct StringFolding {
+    pub ignore_case: bool,
+    pub normal_form: Option<NormalForm>,
+    pub ignore_accents: bool,
+}
+
+impl StringFolding {
+    pub fn is_exact(&self) -> bool {
+        *self == StringFolding::default()
+    }
+
+    pub fn fold(&self, value: &str) -> String {
+        let mut folded = if self.ignore_case {
+            fold_case(value)
+        } else {
+            String::from(value)
+        };
+        if let Some(normal_form) = self.normal_form {
+            folded = normalize(&folded, normal_form);
+        }
+        if self.ignore_accents {
+            folded = strip_diacritics(&folded);
+        }
+        folded
+    }
+}
+
 pub enum JsonValueMatcher {
     String(String),
-    Number(i64),
+    FoldedString(String, StringFolding),
+    Number(Number),
     Boolean(bool),
     Null,
+    Object(Vec<(PropName, JsonValueMatcher)>),
+    Array(Vec<JsonValueMatcher>),
+}
+
+pub struct JsonValueRange {
+    pub start: Option<JsonValueMatcher>,
+    pub end: Option<JsonValueMatcher>,
+    pub is_end_inclusive: bool,
+}
+
+impl JsonValueRange {
+    pub fn contains(&self, value: &JsonValue) -> bool {
+        self.start
+            .as_ref()
+            .map(|start| {
+                matches!(
+                    compare_numeric_value(value, start),
+                    Some(Ordering::Greater) | Some(Ordering::Equal)
+                )
+            })
+            .unwrap_or_else(|| value.is_number())
+            && self
+                .end
+                .as_ref()
+                .map(|end| match compare_numeric_value(value, end) {
+                    Some(Ordering::Less) => true,
+                    Some(Ordering::Equal) => self.is_end_inclusive,
+                    _ => false,
+                })
+                .unwrap_or_else(|| value.is_number())
+    }
+}
+
+/// Values are looked up by a canonical key, so numbers which are equal by value, such as
+/// `1` and `1.0`, share a key. Strings are kept apart by how their matcher folds them, so
+/// that a string is only folded to look up the members which asked for that folding.
+#[derive(Default)]
+pub struct JsonValueSet {
+    keys: HashSet<String>,
+    strings: Vec<(StringFolding, HashSet<String>)>,
+}
+
+impl JsonValueSet {
+    /// Only strings, numbers, booleans and null can be members of a set.
+    pub fn insert(&mut self, json_value_matcher: &JsonValueMatcher) -> bool {
+        match json_value_matcher {
+            JsonValueMatcher::String(value) => {
+                self.strings_folded_by(StringFolding::default())
+                    .insert(value.clone());
+            }
+            JsonValueMatcher::FoldedString(value, folding) => {
+                self.strings_folded_by(*folding).insert(value.clone());
+            }
+            JsonValueMatcher::Number(number) => {
+                self.keys.insert(number_key(number));
+            }
+            JsonValueMatcher::Boolean(value) => {
+                self.keys.insert(format!("b:{}", value));
+            }
+            JsonValueMatcher::Null => {
+                self.keys.insert(String::from("null"));
+            }
+            JsonValueMatcher::Object(_) | JsonValueMatcher::Array(_) => return false,
+        };
+        true
+    }
+
+    fn strings_folded_by(&mut self, folding: StringFolding) -> &mut HashSet<String> {
+        let index = match self
+            .strings
+            .iter()
+            .position(|(string_folding, _)| *string_folding == folding)
+        {
+            Some(index) => index,
+            None => {
+                self.strings.push((folding, HashSet::new()));
+                self.strings.len() - 1
+            }
+        };
+        &mut self.strings[index].1
+    }
+
+    pub fn contains(&self, value: &JsonValue) -> bool {
+        match value {
+            JsonValue::String(_) | JsonValue::Short(_) => {
+                let string_value = value.as_str().unwrap_or_default();
+                self.strings
+                    .iter()
+                    .any(|(folding, strings)| strings.contains(&folding.fold(string_value)))
+            }
+            JsonValue::Number(number) => self.keys.contains(&number_key(number)),
+            JsonValue::Boolean(boolean) => self.keys.contains(&format!("b:{}", boolean)),
+            JsonValue::Null => self.keys.contains("null"),
+            JsonValue::Object(_) | JsonValue::Array(_) => false,
+        }
+    }
+}
+
+fn number_key(number: &Number) -> String {
+    let (is_positive, mantissa, _) = number.as_parts();
+    if mantissa == 0 {
+        return String::from("n:0");
+    }
+
+    let (magnitude, significant) = significant_digits(number);
+    format!(
+        "n:{}{}e{}",
+        if is_positive { "" } else { "-" },
+        significant,
+        magnitude
+    )
 }
 
 pub enum JsonValueMemberMatcher {
@@ -11,54 +170,376 @@ pub enum JsonValueMemberMatcher {
     Prefixed(JsonValueMatcher),
     Suffixed(JsonValueMatcher),
     Contains(JsonValueMatcher),
+    NotEqual(JsonValueMatcher),
+    GreaterThan(JsonValueMatcher),
+    GreaterThanOrEqual(JsonValueMatcher),
+    LessThan(JsonValueMatcher),
+    LessThanOrEqual(JsonValueMatcher),
+    Range(JsonValueRange),
+    Regex(Regex),
+    Fuzzy {
+        value: JsonValueMatcher,
+        max_distance: usize,
+    },
+    In(JsonValueSet),
 }
 
-fn identify_member_matcher(
-    cap: &regex::Captures,
-    member: JsonValueMatcher,
-) -> Result<JsonValueMemberMatcher, ()> {
-    match cap.name("matchingStrategy").map(|value| value.as_str()) {
-        Some("~=") | Some("~:") => Ok(JsonValueMemberMatcher::ContainsExact(member)),
-        Some("^=") | Some("^:") => Ok(JsonValueMemberMatcher::Prefixed(member)),
-        Some("$=") | Some("$:") => Ok(JsonValueMemberMatcher::Suffixed(member)),
-        Some("=") | Some(":") => Ok(JsonValueMemberMatcher::Exact(member)),
-        Some("*=") | Some("*:") => Ok(JsonValueMemberMatcher::Contains(member)),
-        _ => Err(()),
+/// Objects match when they have at least the matched properties, while arrays must have
+/// exactly the matched members in the same order.
+pub fn value_matches_exact(value: &JsonValue, json_value_matcher: &JsonValueMatcher) -> bool {
+    match (value, json_value_matcher) {
+        (_, JsonValueMatcher::String(_)) | (_, JsonValueMatcher::FoldedString(_, _)) => {
+            string_value_satisfies(value, json_value_matcher, |string_value, matched_value| {
+                string_value == matched_value
+            })
+        }
+        (JsonValue::Number(number_value), JsonValueMatcher::Number(matched_value)) => {
+            compare_numbers(number_value, matched_value) == Some(Ordering::Equal)
+        }
+        (JsonValue::Boolean(bool_value), JsonValueMatcher::Boolean(matched_value)) => {
+            bool_value.eq(matched_value)
+        }
+        (JsonValue::Null, JsonValueMatcher::Null) => true,
+        (JsonValue::Object(object), JsonValueMatcher::Object(matched_members)) => {
+            matched_members.iter().all(|(matched_name, matched_value)| {
+                object.iter().any(|(name, value)| {
+                    matched_name.matches(name) && value_matches_exact(value, matched_value)
+                })
+            })
+        }
+        (JsonValue::Array(array), JsonValueMatcher::Array(matched_members)) => {
+            array.len() == matched_members.len()
+                && array
+                    .iter()
+                    .zip(matched_members)
+                    .all(|(value, matched_value)| value_matches_exact(value, matched_value))
+        }
+        (_, _) => false,
     }
 }
 
-fn identify_string_matcher(cap: &regex::Captures) -> Option<Result<JsonValueMatcher, ()>> {
-    cap.name("stringValue")
-        .map(|value| Ok(JsonValueMatcher::String(String::from(value.as_str()))))
+/// Compares two numbers exactly, using the decimal digits and exponent the json parser
+/// produced rather than a lossy floating point conversion, so integers beyond 2^53 and
+/// beyond `i64::MAX` are still ordered correctly.
+pub fn compare_numbers(left: &Number, right: &Number) -> Option<Ordering> {
+    if left.is_nan() || right.is_nan() {
+        return None;
+    }
+
+    let signum = |number: &Number| match number.as_parts() {
+        (_, 0, _) => 0,
+        (true, _, _) => 1,
+        (false, _, _) => -1,
+    };
+
+    Some(match signum(left).cmp(&signum(right)) {
+        Ordering::Equal => match signum(left) {
+            0 => Ordering::Equal,
+            1 => compare_magnitudes(left, right),
+            _ => compare_magnitudes(right, left),
+        },
+        ordering => ordering,
+    })
+}
+
+/// The magnitude and significant digits of a number, which are the same for every way of
+/// writing the same value.
+fn significant_digits(number: &Number) -> (i32, String) {
+    let (_, mantissa, exponent) = number.as_parts();
+    let digits = mantissa.to_string();
+    let significant = digits.trim_end_matches('0');
+    let magnitude = i32::from(exponent) + digits.len() as i32;
+    (magnitude, String::from(significant))
 }
 
-fn identify_number_matcher(cap: &regex::Captures) -> Option<Result<JsonValueMatcher, ()>> {
-    cap.name("numberValue")
-        .map(|value| match String::from(value.as_str()).parse::<i64>() {
-            Ok(number_value) => Ok(JsonValueMatcher::Number(number_value)),
-            Err(_) => Err(()),
-        })
+fn compare_magnitudes(left: &Number, right: &Number) -> Ordering {
+    let (left_magnitude, left_digits) = significant_digits(left);
+    let (right_magnitude, right_digits) = significant_digits(right);
+    left_magnitude
+        .cmp(&right_magnitude)
+        .then_with(|| left_digits.cmp(&right_digits))
 }
 
-fn identify_literal_matcher(cap: &regex::Captures) -> Option<Result<JsonValueMatcher, ()>> {
-    cap.name("literalValue").map(|value| match value.as_str() {
-        "true" => Ok(JsonValueMatcher::Boolean(true)),
-        "false" => Ok(JsonValueMatcher::Boolean(false)),
-        "null" => Ok(JsonValueMatcher::Null),
+pub fn compare_numeric_value(
+    value: &JsonValue,
+    json_value_matcher: &JsonValueMatcher,
+) -> Option<Ordering> {
+    match (value, json_value_matcher) {
+        (JsonValue::Number(number_value), JsonValueMatcher::Number(matched_value)) => {
+            compare_numbers(number_value, matched_value)
+        }
+        (_, _) => None,
+    }
+}
+
+pub fn value_satisfies(
+    value: &JsonValue,
+    json_value_member_matcher: &JsonValueMemberMatcher,
+) -> bool {
+    match json_value_member_matcher {
+        JsonValueMemberMatcher::NotEqual(json_value_matcher) => {
+            !value_matches_exact(value, json_value_matcher)
+        }
+        JsonValueMemberMatcher::GreaterThan(json_value_matcher) => {
+            compare_numeric_value(value, json_value_matcher) == Some(Ordering::Greater)
+        }
+        JsonValueMemberMatcher::GreaterThanOrEqual(json_value_matcher) => matches!(
+            compare_numeric_value(value, json_value_matcher),
+            Some(Ordering::Greater) | Some(Ordering::Equal)
+        ),
+        JsonValueMemberMatcher::LessThan(json_value_matcher) => {
+            compare_numeric_value(value, json_value_matcher) == Some(Ordering::Less)
+        }
+        JsonValueMemberMatcher::LessThanOrEqual(json_value_matcher) => matches!(
+            compare_numeric_value(value, json_value_matcher),
+            Some(Ordering::Less) | Some(Ordering::Equal)
+        ),
+        JsonValueMemberMatcher::Range(range) => range.contains(value),
+        JsonValueMemberMatcher::In(values) => values.contains(value),
+        JsonValueMemberMatcher::Regex(regex) => value
+            .as_str()
+            .map(|string_value| regex.is_match(string_value))
+            .unwrap_or(false),
+        JsonValueMemberMatcher::Fuzzy {
+            value: json_value_matcher,
+            max_distance,
+        } => string_value_satisfies(value, json_value_matcher, |string_value, matched_value| {
+            let (length, matched_length) =
+                (string_value.chars().count(), matched_value.chars().count());
+            length.max(matched_length) - length.min(matched_length) <= *max_distance
+                && edit_distance(string_value, matched_value) <= *max_distance
+        }),
+        JsonValueMemberMatcher::Exact(json_value_matcher) => {
+            value_matches_exact(value, json_value_matcher)
+        }
+        JsonValueMemberMatcher::ContainsExact(json_value_matcher) => {
+            string_value_satisfies(value, json_value_matcher, |string_value, matched_value| {
+                string_value
+                    .split_whitespace()
+                    .any(|word| word == matched_value)
+            })
+        }
+        JsonValueMemberMatcher::Prefixed(json_value_matcher) => {
+            string_value_satisfies(value, json_value_matcher, |string_value, matched_value| {
+                string_value.starts_with(matched_value)
+            })
+        }
+        JsonValueMemberMatcher::Suffixed(json_value_matcher) => {
+            string_value_satisfies(value, json_value_matcher, |string_value, matched_value| {
+                string_value.ends_with(matched_value)
+            })
+        }
+        JsonValueMemberMatcher::Contains(json_value_matcher) => {
+            string_value_satisfies(value, json_value_matcher, |string_value, matched_value| {
+                string_value.contains(matched_value)
+            })
+        }
+    }
+}
+
+fn string_value_satisfies(
+    value: &JsonValue,
+    json_value_matcher: &JsonValueMatcher,
+    predicate: impl Fn(&str, &str) -> bool,
+) -> bool {
+    match (value.as_str(), json_value_matcher) {
+        (Some(string_value), JsonValueMatcher::String(matched_value)) => {
+            predicate(string_value, matched_value)
+        }
+        (Some(string_value), JsonValueMatcher::FoldedString(matched_value, folding)) => {
+            predicate(&folding.fold(string_value), matched_value)
+        }
+        _ => false,
+    }
+}
+
+/// The Levenshtein distance between two strings, counted in characters rather than bytes.
+pub fn edit_distance(left: &str, right: &str) -> usize {
+    let right: Vec<char> = right.chars().collect();
+    let mut distances: Vec<usize> = (0..=right.len()).collect();
+    for (left_index, left_character) in left.chars().enumerate() {
+        let mut diagonal = distances[0];
+        distances[0] = left_index + 1;
+        for (right_index, right_character) in right.iter().enumerate() {
+            let substitution = diagonal + usize::from(left_character != *right_character);
+            diagonal = distances[right_index + 1];
+            distances[right_index + 1] = substitution
+                .min(distances[right_index] + 1)
+                .min(diagonal + 1);
+        }
+    }
+    distances[right.len()]
+}
+
+pub fn count_satisfies(count: usize, json_value_member_matcher: &JsonValueMemberMatcher) -> bool {
+    let count = JsonValue::from(count);
+    match json_value_member_matcher {
+        JsonValueMemberMatcher::Exact(json_value_matcher) => {
+            value_matches_exact(&count, json_value_matcher)
+        }
+        json_value_comparison => value_satisfies(&count, json_value_comparison),
+    }
+}
+
+pub fn member_matcher_for(
+    matching_strategy: &str,
+    member: JsonValueMatcher,
+) -> Option<JsonValueMemberMatcher> {
+    match matching_strategy {
+        "~=" | "~:" => Some(JsonValueMemberMatcher::ContainsExact(member)),
+        "^=" | "^:" => Some(JsonValueMemberMatcher::Prefixed(member)),
+        "$=" | "$:" => Some(JsonValueMemberMatcher::Suffixed(member)),
+        "=" | ":" => Some(JsonValueMemberMatcher::Exact(member)),
+        "*=" | "*:" => Some(JsonValueMemberMatcher::Contains(member)),
+        "!=" | "!:" => Some(JsonValueMemberMatcher::NotEqual(member)),
+        ">" | ">:" => Some(JsonValueMemberMatcher::GreaterThan(member)),
+        ">=" | ">=:" => Some(JsonValueMemberMatcher::GreaterThanOrEqual(member)),
+        "<" | "<:" => Some(JsonValueMemberMatcher::LessThan(member)),
+        "<=" | "<=:" => Some(JsonValueMemberMatcher::LessThanOrEqual(member)),
+        _ => None,
+    }
+}
+
+pub fn parse_json_string(literal: &str) -> Result<String, ()> {
+    match json::parse(literal) {
+        Ok(JsonValue::String(string_value)) => Ok(string_value),
+        Ok(JsonValue::Short(string_value)) => Ok(String::from(string_value.as_str())),
         _ => Err(()),
-    })
+    }
 }
 
-pub fn identify_value_matcher(cap: &regex::Captures) -> Result<Option<JsonValueMemberMatcher>, ()> {
-    match identify_string_matcher(cap)
-        .or(identify_number_matcher(cap))
-        .or(identify_literal_matcher(cap))
-    {
-        Some(Ok(json_value_matcher)) => match identify_member_matcher(cap, json_value_matcher) {
-            Ok(match_strategy) => Ok(Some(match_strategy)),
-            _ => Err(()),
-        },
-        Some(Err(_)) => Err(()),
-        None => Ok(None),
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn number(value: &str) -> Number {
+        match json::parse(value) {
+            Ok(JsonValue::Number(number)) => number,
+            _ => panic!("Invalid number"),
+        }
+    }
+
+    #[test]
+    fn should_compare_numbers_by_value() {
+        assert_eq!(
+            compare_numbers(&number("1"), &number("1.0")),
+            Some(Ordering::Equal)
+        );
+        assert_eq!(
+            compare_numbers(&number("1e0"), &number("10e-1")),
+            Some(Ordering::Equal)
+        );
+        assert_eq!(
+            compare_numbers(&number("0"), &number("-0.0")),
+            Some(Ordering::Equal)
+        );
+        assert_eq!(
+            compare_numbers(&number("-3"), &number("-2.5")),
+            Some(Ordering::Less)
+        );
+        assert_eq!(
+            compare_numbers(&number("0.123"), &number("0.12")),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(
+            compare_numbers(&number("-1"), &number("0")),
+            Some(Ordering::Less)
+        );
+    }
+
+    #[test]
+    fn should_compare_integers_beyond_i64_exactly() {
+        assert_eq!(
+            compare_numbers(
+                &number("18446744073709551615"),
+                &number("18446744073709551614")
+            ),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(
+            compare_numbers(
+                &number("9223372036854775808"),
+                &number("9223372036854775808.0")
+            ),
+            Some(Ordering::Equal)
+        );
+        assert_eq!(
+            compare_numbers(&number("9007199254740993"), &number("9007199254740992")),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(
+            compare_numbers(&number("-9007199254740993"), &number("-9007199254740992")),
+            Some(Ordering::Less)
+        );
+    }
+
+    #[test]
+    fn should_compare_decimals_without_losing_precision() {
+        assert_eq!(
+            compare_numbers(
+                &number("1234567890.123456789"),
+                &number("1234567890.123456788")
+            ),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(
+            compare_numbers(&number("0.1"), &number("1e-1")),
+            Some(Ordering::Equal)
+        );
+    }
+
+    #[test]
+    fn should_fold_case_of_characters_without_a_single_character_counterpart() {
+        assert_eq!(fold_case("Straße"), fold_case("STRASSE"));
+        assert_eq!(fold_case("ẞ"), "ss");
+        assert_eq!(fold_case("ΟΔΥΣΣΕΥΣ"), fold_case("οδυσσευς"));
+        assert_eq!(fold_case("ΟΔΥΣ"), "οδυσ");
+        assert_eq!(fold_case("οδυς"), "οδυσ");
+    }
+
+    #[test]
+    fn should_count_edits_between_strings_in_characters() {
+        assert_eq!(edit_distance("jeff goldblum", "jeff goldblum"), 0);
+        assert_eq!(edit_distance("jef goldblum", "jeff goldblum"), 1);
+        assert_eq!(edit_distance("jeff goldblum", "geoff goldbloom"), 4);
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("Zürich", "Zurich"), 1);
+    }
+
+    #[test]
+    fn should_find_set_members_equal_by_value() {
+        let mut values = JsonValueSet::default();
+        values.insert(&JsonValueMatcher::Number(number("401")));
+        values.insert(&JsonValueMatcher::Number(number("-0.5")));
+        values.insert(&JsonValueMatcher::String(String::from("401")));
+        values.insert(&JsonValueMatcher::Null);
+
+        assert!(values.contains(&JsonValue::Number(number("4.01e2"))));
+        assert!(values.contains(&JsonValue::Number(number("-5e-1"))));
+        assert!(values.contains(&JsonValue::from("401")));
+        assert!(values.contains(&JsonValue::Null));
+        assert!(!values.contains(&JsonValue::Number(number("0.5"))));
+        assert!(!values.contains(&JsonValue::Number(number("4010"))));
+        assert!(!values.contains(&JsonValue::from(false)));
+        assert!(!values.insert(&JsonValueMatcher::Array(vec![])));
+    }
+
+    #[test]
+    fn should_fold_strings_as_each_set_member_requires() {
+        let folding = StringFolding {
+            ignore_case: true,
+            ..StringFolding::default()
+        };
+        let mut values = JsonValueSet::default();
+        values.insert(&JsonValueMatcher::String(String::from("error")));
+        values.insert(&JsonValueMatcher::FoldedString(
+            folding.fold("FATAL"),
+            folding,
+        ));
+
+        assert!(values.contains(&JsonValue::from("error")));
+        assert!(!values.contains(&JsonValue::from("ERROR")));
+        assert!(!values.contains(&JsonValue::from("Error")));
+        assert!(values.contains(&JsonValue::from("Fatal")));
     }
 }
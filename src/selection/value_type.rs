@@ -0,0 +1,123 @@
+use json::JsonValue;
+
+use super::SelectionLens;
+
+#[derive(Debug, PartialEq)]
+pub enum JsonType {
+    String,
+    Number,
+    Boolean,
+    Null,
+    Object,
+    Array,
+}
+
+impl JsonType {
+    pub fn from_name(name: &str) -> Option<JsonType> {
+        match name {
+            "string" => Some(JsonType::String),
+            "number" => Some(JsonType::Number),
+            "boolean" => Some(JsonType::Boolean),
+            "null" => Some(JsonType::Null),
+            "object" => Some(JsonType::Object),
+            "array" => Some(JsonType::Array),
+            _ => None,
+        }
+    }
+
+    fn of(value: &JsonValue) -> JsonType {
+        match value {
+            JsonValue::Short(_) | JsonValue::String(_) => JsonType::String,
+            JsonValue::Number(_) => JsonType::Number,
+            JsonValue::Boolean(_) => JsonType::Boolean,
+            JsonValue::Null => JsonType::Null,
+            JsonValue::Object(_) => JsonType::Object,
+            JsonValue::Array(_) => JsonType::Array,
+        }
+    }
+}
+
+pub struct ValueType {
+    pub json_type: JsonType,
+}
+
+impl SelectionLens for ValueType {
+    fn select<'a>(&self, input: Option<&'a JsonValue>) -> Option<&'a JsonValue> {
+        input.filter(|value| JsonType::of(value) == self.json_type)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::selection::{match_filters, select_from_slice};
+    use json::array;
+    use json::object;
+
+    #[test]
+    fn should_match_value_of_the_specified_type() {
+        let data = &object! {
+            "user_id" => "42",
+            "amount" => 9.99,
+            "meta" => object! {},
+            "tags" => array![],
+            "deleted_at" => JsonValue::Null,
+            "active" => true
+        };
+
+        for (pattern, prop) in &[
+            (".user_id:string", "user_id"),
+            (".amount:number", "amount"),
+            (".meta:object", "meta"),
+            (".tags:array", "tags"),
+            (".deleted_at:null", "deleted_at"),
+            (".active:boolean", "active"),
+        ] {
+            match match_filters(pattern) {
+                Ok(matchers) => assert_eq!(
+                    matchers[1].select(matchers[0].select(Some(data))),
+                    Some(&data[*prop])
+                ),
+                _ => panic!("Invalid result"),
+            }
+        }
+
+        match match_filters(".user_id:number") {
+            Ok(matchers) => assert_eq!(matchers[1].select(matchers[0].select(Some(data))), None),
+            _ => panic!("Invalid result"),
+        }
+    }
+
+    #[test]
+    fn should_match_object_missing_prop() {
+        let data = &object! {
+            "user" => object! { "name" => "jeff goldblum" }
+        };
+
+        match match_filters(".user.email:missing") {
+            Ok(matchers) => assert_eq!(select_from_slice(&matchers, data), Some(&data["user"])),
+            _ => panic!("Invalid result"),
+        }
+
+        match match_filters(".user.name:missing") {
+            Ok(matchers) => assert_eq!(select_from_slice(&matchers, data), None),
+            _ => panic!("Invalid result"),
+        }
+
+        match match_filters(".email:missing") {
+            Ok(matchers) => assert_eq!(
+                select_from_slice(&matchers, &array![object! { "name" => "jeff goldblum" }]),
+                None
+            ),
+            _ => panic!("Invalid result"),
+        }
+    }
+
+    #[test]
+    fn should_return_none_when_json_isnt_present() {
+        let value_type = ValueType {
+            json_type: JsonType::Null,
+        };
+        assert_eq!(value_type.select(None), None);
+    }
+}
@@ -0,0 +1,40 @@
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+
+use super::value_matchers::parse_json_string;
+
+/// Values files hold one string per line. Lines are read as they are, so that ids such as
+/// `12345` match the strings they are usually stored as, unless they are written as a
+/// quoted JSON string to keep surrounding spaces or use escapes.
+pub fn read_values(path: &str) -> Result<Vec<String>, io::Error> {
+    let mut values = vec![];
+    for line in BufReader::new(File::open(path)?).lines() {
+        let line = line?;
+        let line = line.trim();
+        match parse_json_string(line) {
+            _ if line.is_empty() => {}
+            Ok(string_value) => values.push(string_value),
+            Err(_) => values.push(String::from(line)),
+        }
+    }
+    Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    #[test]
+    fn should_read_one_string_per_line() {
+        let mut tmp_file = tempfile::NamedTempFile::new().unwrap();
+        tmp_file
+            .write_all(b"acc-001\n  \" acc 002\"  \n\n12345\n\"43\"\nnull\n[1]\n")
+            .unwrap();
+
+        assert_eq!(
+            read_values(tmp_file.path().to_str().unwrap()).unwrap(),
+            vec!["acc-001", " acc 002", "12345", "43", "null", "[1]"]
+        );
+    }
+}
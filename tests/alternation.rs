@@ -0,0 +1,86 @@
+#[cfg(test)]
+mod cli {
+    use std::process::Command;
+
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+
+    #[test]
+    fn should_match_either_branch_of_a_top_level_alternation() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(r#"{"level":"error"}|{"level":"fatal"}"#);
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "{\"level\":\"info\"}\n
+{\"level\":\"fatal\"}\n
+{\"level\":\"error\"}\n",
+        );
+
+        assert_cmd
+            .assert()
+            .success()
+            .stdout("{\"level\":\"fatal\"}\n{\"level\":\"error\"}\n");
+    }
+
+    #[test]
+    fn should_match_alternation_nested_under_a_prop() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(".user(.email|.phone)");
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "{\"user\":{\"name\":\"jeff goldblum\"}}\n
+{\"user\":{\"phone\":\"555-0100\"}}\n
+{\"email\":\"jeff@example.com\"}\n",
+        );
+
+        assert_cmd
+            .assert()
+            .success()
+            .stdout("{\"user\":{\"phone\":\"555-0100\"}}\n");
+    }
+
+    #[test]
+    fn should_match_alternation_inside_a_sequence() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(r#".logs[{"level":"error"}|{"level":"fatal"}]"#);
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "{\"logs\":[{\"level\":\"info\"}]}\n
+{\"logs\":[{\"level\":\"info\"},{\"level\":\"fatal\"}]}\n",
+        );
+
+        assert_cmd
+            .assert()
+            .success()
+            .stdout("{\"logs\":[{\"level\":\"info\"},{\"level\":\"fatal\"}]}\n");
+    }
+
+    #[test]
+    fn should_continue_pattern_after_a_group() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(".contact(.home|.work).phone");
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "{\"contact\":{\"home\":{\"email\":\"a@example.com\"},\"work\":{\"phone\":\"555-0100\"}}}\n
+{\"contact\":{\"home\":{\"email\":\"a@example.com\"}}}\n",
+        );
+
+        assert_cmd.assert().success().stdout(
+            "{\"contact\":{\"home\":{\"email\":\"a@example.com\"},\"work\":{\"phone\":\"555-0100\"}}}\n",
+        );
+    }
+
+    #[test]
+    fn should_fail_on_an_unclosed_group() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(".user(.email|.phone");
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "expected \")\" to close the group",
+        ));
+    }
+}
@@ -212,4 +212,82 @@ mod cli {
             .success()
             .stdout("{\"people\":[{\"name\":\"jeff goldblum\"}]}\n");
     }
+
+    #[test]
+    fn should_match_last_member_with_negative_index() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(r#".retries[-1]{"status":"ok"}"#);
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "{\"retries\":[{\"status\":\"ok\"},{\"status\":\"failed\"}]}\n
+{\"retries\":[{\"status\":\"failed\"},{\"status\":\"ok\"}]}\n",
+        );
+
+        assert_cmd
+            .assert()
+            .success()
+            .stdout("{\"retries\":[{\"status\":\"failed\"},{\"status\":\"ok\"}]}\n");
+    }
+
+    #[test]
+    fn should_match_any_member_within_slice() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(r#".frames[-2:]{"file":"main.rs"}"#);
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "{\"frames\":[{\"file\":\"main.rs\"},{\"file\":\"lib.rs\"},{\"file\":\"io.rs\"}]}\n
+{\"frames\":[{\"file\":\"lib.rs\"},{\"file\":\"main.rs\"},{\"file\":\"io.rs\"}]}\n",
+        );
+
+        assert_cmd.assert().success().stdout(
+            "{\"frames\":[{\"file\":\"lib.rs\"},{\"file\":\"main.rs\"},{\"file\":\"io.rs\"}]}\n",
+        );
+    }
+
+    #[test]
+    fn should_not_match_empty_slice() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg("[0:0]");
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer("[1,2,3]\n");
+
+        assert_cmd.assert().failure();
+    }
+
+    #[test]
+    fn should_match_array_containing_members_in_order() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(r#".events[="login", ="logout"]"#);
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "{\"events\":[\"logout\",\"login\"]}\n
+{\"events\":[\"login\",\"view\",\"logout\"]}\n",
+        );
+
+        assert_cmd
+            .assert()
+            .success()
+            .stdout("{\"events\":[\"login\",\"view\",\"logout\"]}\n");
+    }
+
+    #[test]
+    fn should_match_whole_array() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(r#".path[=="api","v1"]"#);
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "{\"path\":[\"api\",\"v1\",\"users\"]}\n
+{\"path\":[\"api\",\"v1\"]}\n",
+        );
+
+        assert_cmd
+            .assert()
+            .success()
+            .stdout("{\"path\":[\"api\",\"v1\"]}\n");
+    }
 }
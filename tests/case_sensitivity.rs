@@ -42,4 +42,81 @@ mod cli {
 {\"NAME\":\"jeff goldblum\"}\n",
         );
     }
+
+    #[test]
+    fn should_ignore_the_case_of_values_followed_by_the_i_flag() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(r#"{"name"*:"GOLDBLUM"i}"#);
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "{\"name\":\"jeff goldblum\"}
+{\"name\":\"JEFF Goldblum\"}
+{\"NAME\":\"jeff goldblum\"}\n",
+        );
+
+        assert_cmd.assert().success().stdout(
+            "{\"name\":\"jeff goldblum\"}
+{\"name\":\"JEFF Goldblum\"}\n",
+        );
+    }
+
+    #[test]
+    fn should_ignore_the_case_of_property_names_followed_by_the_i_flag() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(r#"{"name"i:"jeff goldblum"}"#);
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "{\"name\":\"jeff goldblum\"}
+{\"name\":\"JEFF goldblum\"}
+{\"NAME\":\"jeff goldblum\"}\n",
+        );
+
+        assert_cmd.assert().success().stdout(
+            "{\"name\":\"jeff goldblum\"}
+{\"NAME\":\"jeff goldblum\"}\n",
+        );
+    }
+
+    #[test]
+    fn should_fold_case_using_unicode_case_folding() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(r#"{"street"$:"STRASSE"i}"#);
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "{\"street\":\"Hauptstraße\"}
+{\"street\":\"Main Street\"}\n",
+        );
+
+        assert_cmd
+            .assert()
+            .success()
+            .stdout("{\"street\":\"Hauptstraße\"}\n");
+    }
+
+    #[test]
+    fn should_fold_final_sigma_in_partial_matches() {
+        for pattern in &[
+            r#"{"n"^:"οδυσ"i}"#,
+            r#"{"n"^:"ΟΔΥΣ"i}"#,
+            r#"{"n"*:"ΔΥΣΣ"i}"#,
+            r#"{"n"$:"σευσ"i}"#,
+        ] {
+            let mut cmd = Command::main_binary().unwrap();
+
+            cmd.arg(pattern);
+            let mut stdin_cmd = cmd.with_stdin();
+            let mut assert_cmd = stdin_cmd.buffer(
+                "{\"n\":\"ΟΔΥΣΣΕΥΣ\"}
+{\"n\":\"ΑΧΙΛΛΕΥΣ\"}\n",
+            );
+
+            assert_cmd
+                .assert()
+                .success()
+                .stdout("{\"n\":\"ΟΔΥΣΣΕΥΣ\"}\n");
+        }
+    }
 }
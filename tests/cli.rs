@@ -99,4 +99,26 @@ mod cli {
 "#,
         );
     }
+
+    #[test]
+    fn should_point_at_the_column_where_the_pattern_is_invalid() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(".people[.name");
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "Invalid filter: \".people[.name\"
+.people[.name
+             ^ expected \"]\" to close the array matcher, found the end of the pattern",
+        ));
+    }
+
+    #[test]
+    fn should_describe_what_was_expected_when_the_pattern_is_invalid() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(r#"{"name"=:"jeff goldblum"}"#);
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "       ^ expected a matching strategy (one of : ~: ^: $: *: !: >: >=: <: <=: ~), found \"=:\"",
+        ));
+    }
 }
@@ -45,4 +45,22 @@ mod cli {
             r#"}"#
         ));
     }
+
+    #[test]
+    fn should_color_the_original_case_of_json_matched_case_insensitively() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg("--color=always");
+        cmd.arg("-i").arg(r#"{"name":"jeff goldblum"}"#);
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer("{\"NAME\":\"Jeff Goldblum\"}\n");
+
+        assert_cmd.assert().success().stdout(format!(
+            r#"{}{}{}
+"#,
+            r#"{"NAME":"#,
+            r#""Jeff Goldblum""#.red(),
+            r#"}"#
+        ));
+    }
 }
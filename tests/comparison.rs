@@ -0,0 +1,187 @@
+#[cfg(test)]
+mod cli {
+    use std::process::Command;
+
+    use assert_cmd::prelude::*;
+
+    #[test]
+    fn should_match_json_property_greater_than_value() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(r#"{"latency_ms">:500}"#);
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "{\"path\":\"/\",\"latency_ms\":120}
+{\"path\":\"/search\",\"latency_ms\":950}
+{\"path\":\"/login\",\"latency_ms\":\"950\"}\n",
+        );
+
+        assert_cmd
+            .assert()
+            .success()
+            .stdout("{\"path\":\"/search\",\"latency_ms\":950}\n");
+    }
+
+    #[test]
+    fn should_match_nested_json_property_greater_than_or_equal_to_value() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(r#".response{"status">=:500}"#);
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "{\"response\":{\"status\":200}}
+{\"response\":{\"status\":404}}
+{\"response\":{\"status\":500}}
+{\"response\":{\"status\":503}}\n",
+        );
+
+        assert_cmd.assert().success().stdout(
+            "{\"response\":{\"status\":500}}
+{\"response\":{\"status\":503}}\n",
+        );
+    }
+
+    #[test]
+    fn should_match_json_property_not_equal_to_value() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(r#"{"status"!:200}"#);
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "{\"status\":200}
+{\"status\":404}
+{\"code\":404}\n",
+        );
+
+        assert_cmd.assert().success().stdout("{\"status\":404}\n");
+    }
+
+    #[test]
+    fn should_match_array_with_member_less_than_or_equal_to_value() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(r#".retries[<=10]"#);
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "{\"retries\":[25,50]}
+{\"retries\":[50,10]}\n",
+        );
+
+        assert_cmd
+            .assert()
+            .success()
+            .stdout("{\"retries\":[50,10]}\n");
+    }
+
+    #[test]
+    fn should_match_json_property_within_range() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(r#"{"status":500..599}"#);
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "{\"status\":200}
+{\"status\":500}
+{\"status\":599}
+{\"status\":600}\n",
+        );
+
+        assert_cmd.assert().success().stdout(
+            "{\"status\":500}
+{\"status\":599}\n",
+        );
+    }
+
+    #[test]
+    fn should_match_json_property_within_inclusive_range() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(r#".timings{"latency_ms":100..=250}"#);
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "{\"timings\":{\"latency_ms\":99}}
+{\"timings\":{\"latency_ms\":250}}
+{\"timings\":{\"latency_ms\":251}}\n",
+        );
+
+        assert_cmd
+            .assert()
+            .success()
+            .stdout("{\"timings\":{\"latency_ms\":250}}\n");
+    }
+
+    #[test]
+    fn should_match_json_property_within_exclusive_range() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(r#".timings{"latency_ms":100..<250}"#);
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "{\"timings\":{\"latency_ms\":100}}
+{\"timings\":{\"latency_ms\":249}}
+{\"timings\":{\"latency_ms\":250}}\n",
+        );
+
+        assert_cmd.assert().success().stdout(
+            "{\"timings\":{\"latency_ms\":100}}
+{\"timings\":{\"latency_ms\":249}}\n",
+        );
+    }
+
+    #[test]
+    fn should_match_array_with_member_within_range() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(r#".latencies[=1..10]"#);
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "{\"latencies\":[25,50]}
+{\"latencies\":[50,9]}\n",
+        );
+
+        assert_cmd
+            .assert()
+            .success()
+            .stdout("{\"latencies\":[50,9]}\n");
+    }
+
+    #[test]
+    fn should_match_json_property_with_fractional_and_negative_numbers() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg("-e").arg(r#"{"temp":-3.5..0}"#);
+        cmd.arg("-e").arg(r#"{"score":0.75}"#);
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "{\"temp\":-3}
+{\"temp\":-4}
+{\"score\":7.5e-1}
+{\"score\":0.7}\n",
+        );
+
+        assert_cmd.assert().success().stdout(
+            "{\"temp\":-3}
+{\"score\":7.5e-1}\n",
+        );
+    }
+
+    #[test]
+    fn should_match_integers_larger_than_i64_exactly() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg("-e").arg(r#"{"trace_id":18446744073709551615}"#);
+        cmd.arg("-e").arg(r#"{"span_id">:9223372036854775808}"#);
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "{\"trace_id\":18446744073709551615}
+{\"trace_id\":18446744073709551614}
+{\"span_id\":9223372036854775809}
+{\"span_id\":9223372036854775808}\n",
+        );
+
+        assert_cmd.assert().success().stdout(
+            "{\"trace_id\":18446744073709551615}
+{\"span_id\":9223372036854775809}\n",
+        );
+    }
+}
@@ -0,0 +1,71 @@
+#[cfg(test)]
+mod cli {
+    use std::process::Command;
+
+    use assert_cmd::prelude::*;
+
+    #[test]
+    fn should_match_object_with_every_prop_value() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(r#"{"level":"error","service":"auth"}"#);
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "{\"level\":\"error\",\"service\":\"billing\"}\n
+{\"level\":\"info\",\"service\":\"auth\"}\n
+{\"level\":\"error\",\"service\":\"auth\"}\n",
+        );
+
+        assert_cmd
+            .assert()
+            .success()
+            .stdout("{\"level\":\"error\",\"service\":\"auth\"}\n");
+    }
+
+    #[test]
+    fn shouldnt_match_conditions_met_by_different_objects() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(r#"{"level":"error","service":"auth"}"#);
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "[{\"level\":\"error\",\"service\":\"billing\"},{\"level\":\"info\",\"service\":\"auth\"}]\n",
+        );
+
+        assert_cmd.assert().failure();
+    }
+
+    #[test]
+    fn should_match_object_with_every_chain_in_a_group() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(".req(.method,.path)");
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "{\"req\":{\"method\":\"GET\"},\"path\":\"/\"}\n
+{\"req\":{\"method\":\"GET\",\"path\":\"/\"}}\n",
+        );
+
+        assert_cmd
+            .assert()
+            .success()
+            .stdout("{\"req\":{\"method\":\"GET\",\"path\":\"/\"}}\n");
+    }
+
+    #[test]
+    fn should_match_conjunction_inside_a_sequence() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(r#".people[{"name":"Anne McClain", "craft":"ISS"}]"#);
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "{\"people\":[{\"name\":\"Anne McClain\",\"craft\":\"Soyuz\"},{\"name\":\"Oleg Kononenko\",\"craft\":\"ISS\"}]}\n
+{\"people\":[{\"name\":\"Anne McClain\",\"craft\":\"ISS\"}]}\n",
+        );
+
+        assert_cmd
+            .assert()
+            .success()
+            .stdout("{\"people\":[{\"name\":\"Anne McClain\",\"craft\":\"ISS\"}]}\n");
+    }
+}
@@ -0,0 +1,52 @@
+#[cfg(test)]
+mod cli {
+    use std::process::Command;
+
+    use assert_cmd::prelude::*;
+
+    #[test]
+    fn should_match_step_at_any_depth_below_prop() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg("^.request..token");
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "{\"request\":{\"headers\":[{\"token\":\"abc\"}]}}\n
+{\"request\":{\"method\":\"GET\"},\"token\":\"abc\"}\n
+{\"request\":{\"token\":\"abc\"}}\n",
+        );
+
+        assert_cmd.assert().success().stdout(
+            "{\"request\":{\"headers\":[{\"token\":\"abc\"}]}}\n{\"request\":{\"token\":\"abc\"}}\n",
+        );
+    }
+
+    #[test]
+    fn should_anchor_only_patterns_starting_with_caret() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg("-e").arg("^.name").arg("-e").arg(".title");
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "{\"user\":{\"name\":\"jeff goldblum\"}}\n
+{\"name\":\"jeff goldblum\"}\n
+{\"user\":{\"title\":\"Mr\"}}\n",
+        );
+
+        assert_cmd
+            .assert()
+            .success()
+            .stdout("{\"name\":\"jeff goldblum\"}\n{\"user\":{\"title\":\"Mr\"}}\n");
+    }
+
+    #[test]
+    fn should_still_anchor_every_pattern_with_match_root_flag() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg("-^").arg(".title");
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer("{\"user\":{\"title\":\"Mr\"}}\n");
+
+        assert_cmd.assert().failure();
+    }
+}
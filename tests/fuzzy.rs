@@ -0,0 +1,58 @@
+#[cfg(test)]
+mod cli {
+    use std::process::Command;
+
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+
+    #[test]
+    fn should_match_prop_values_within_the_edit_distance() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(r#"{"name"~2:"jef goldblum"}"#);
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            r#"{"name":"jeff goldblum"}
+{"name":"jeff golblum"}
+{"name":"geoff goldbloom"}
+{"name":42}
+"#,
+        );
+
+        assert_cmd.assert().success().stdout(
+            r#"{"name":"jeff goldblum"}
+{"name":"jeff golblum"}
+"#,
+        );
+    }
+
+    #[test]
+    fn should_match_array_members_within_the_edit_distance() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(r#".products[~1="SKU-1042"i]"#);
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            r#"{"products":["sku-1024"]}
+{"products":["sku-1043","SKU-2000"]}
+{"products":["SKU-104"]}
+"#,
+        );
+
+        assert_cmd.assert().success().stdout(
+            r#"{"products":["sku-1043","SKU-2000"]}
+{"products":["SKU-104"]}
+"#,
+        );
+    }
+
+    #[test]
+    fn should_only_match_strings_approximately() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(r#"{"status"~1:200}"#);
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "expected a string to match approximately, found a number",
+        ));
+    }
+}
@@ -0,0 +1,70 @@
+#[cfg(test)]
+mod cli {
+    use std::process::Command;
+
+    use assert_cmd::prelude::*;
+
+    #[test]
+    fn should_match_value_under_any_prop() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg("-^").arg(r#".*{"email":"jeff@example.com"}"#);
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "{\"author\":{\"email\":\"anne@example.com\"},\"reviewer\":{\"email\":\"jeff@example.com\"}}\n
+{\"author\":{\"email\":\"anne@example.com\"}}\n",
+        );
+
+        assert_cmd.assert().success().stdout(
+            "{\"author\":{\"email\":\"anne@example.com\"},\"reviewer\":{\"email\":\"jeff@example.com\"}}\n",
+        );
+    }
+
+    #[test]
+    fn should_match_prop_names_by_prefix_glob() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(".http_*");
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "{\"http_status\":200}\n
+{\"status\":200}\n",
+        );
+
+        assert_cmd
+            .assert()
+            .success()
+            .stdout("{\"http_status\":200}\n");
+    }
+
+    #[test]
+    fn should_match_prop_value_under_glob_name() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(r#"{"*_id":"abc"}"#);
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "{\"user_id\":\"xyz\",\"request_id\":\"abc\"}\n
+{\"id\":\"abc\"}\n",
+        );
+
+        assert_cmd
+            .assert()
+            .success()
+            .stdout("{\"user_id\":\"xyz\",\"request_id\":\"abc\"}\n");
+    }
+
+    #[test]
+    fn should_match_quoted_star_literally() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(r#"."*""#);
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "{\"name\":\"jeff goldblum\"}\n
+{\"*\":\"star\"}\n",
+        );
+
+        assert_cmd.assert().success().stdout("{\"*\":\"star\"}\n");
+    }
+}
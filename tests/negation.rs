@@ -0,0 +1,90 @@
+#[cfg(test)]
+mod cli {
+    use std::process::Command;
+
+    use assert_cmd::prelude::*;
+
+    #[test]
+    fn should_match_prop_without_negated_pattern() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg("-^").arg(".user:not(.email)");
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "{\"user\":{\"name\":\"jeff goldblum\",\"email\":\"jeff@example.com\"}}\n
+{\"user\":{\"name\":\"jeff goldblum\"}}\n",
+        );
+
+        assert_cmd
+            .assert()
+            .success()
+            .stdout("{\"user\":{\"name\":\"jeff goldblum\"}}\n");
+    }
+
+    #[test]
+    fn should_match_order_with_items_but_no_shipping_address() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(".order(.items,!.shipping.address)");
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "{\"order\":{\"items\":[1],\"shipping\":{\"address\":\"1 Main St\"}}}\n
+{\"order\":{\"shipping\":{}}}\n
+{\"order\":{\"items\":[1],\"shipping\":{}}}\n",
+        );
+
+        assert_cmd
+            .assert()
+            .success()
+            .stdout("{\"order\":{\"items\":[1],\"shipping\":{}}}\n");
+    }
+
+    #[test]
+    fn should_match_missing_prop_unlike_not_equal_matcher() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg("-^").arg(r#"!{"level":"debug"}"#);
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "{\"level\":\"debug\"}\n
+{\"level\":\"info\"}\n
+{\"msg\":\"no level\"}\n",
+        );
+
+        assert_cmd
+            .assert()
+            .success()
+            .stdout("{\"level\":\"info\"}\n{\"msg\":\"no level\"}\n");
+
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg("-^").arg(r#"{"level"!:"debug"}"#);
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "{\"level\":\"debug\"}\n
+{\"level\":\"info\"}\n
+{\"msg\":\"no level\"}\n",
+        );
+
+        assert_cmd
+            .assert()
+            .success()
+            .stdout("{\"level\":\"info\"}\n");
+    }
+
+    #[test]
+    fn shouldnt_match_input_containing_the_negated_path() {
+        for pattern in &["!.x", ":not(.x)"] {
+            let mut cmd = Command::main_binary().unwrap();
+
+            cmd.arg(pattern);
+            let mut stdin_cmd = cmd.with_stdin();
+            let mut assert_cmd = stdin_cmd.buffer(
+                "{\"x\":1}\n
+{\"y\":1}\n",
+            );
+
+            assert_cmd.assert().success().stdout("{\"y\":1}\n");
+        }
+    }
+}
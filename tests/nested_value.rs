@@ -0,0 +1,58 @@
+#[cfg(test)]
+mod cli {
+    use std::process::Command;
+
+    use assert_cmd::prelude::*;
+
+    #[test]
+    fn should_match_object_value_as_subset() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(r#"{"user":{"role":"admin"}}"#);
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "{\"user\":{\"name\":\"jeff goldblum\",\"role\":\"guest\"}}\n
+{\"user\":{\"name\":\"jeff goldblum\",\"role\":\"admin\"}}\n",
+        );
+
+        assert_cmd
+            .assert()
+            .success()
+            .stdout("{\"user\":{\"name\":\"jeff goldblum\",\"role\":\"admin\"}}\n");
+    }
+
+    #[test]
+    fn should_match_array_value_exactly() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(r#"{"tags":["a","b"]}"#);
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "{\"tags\":[\"a\",\"b\",\"c\"]}\n
+{\"tags\":[\"a\",\"b\"]}\n
+{\"tags\":[\"b\",\"a\"]}\n",
+        );
+
+        assert_cmd
+            .assert()
+            .success()
+            .stdout("{\"tags\":[\"a\",\"b\"]}\n");
+    }
+
+    #[test]
+    fn should_match_array_containing_object_value() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(r#".people[~={"craft":"ISS"}]"#);
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "{\"people\":[{\"name\":\"Anne McClain\",\"craft\":\"Soyuz\"}]}\n
+{\"people\":[{\"name\":\"Oleg Kononenko\",\"craft\":\"ISS\"}]}\n",
+        );
+
+        assert_cmd
+            .assert()
+            .success()
+            .stdout("{\"people\":[{\"name\":\"Oleg Kononenko\",\"craft\":\"ISS\"}]}\n");
+    }
+}
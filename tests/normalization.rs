@@ -0,0 +1,117 @@
+#[cfg(test)]
+mod cli {
+    use std::process::Command;
+
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+
+    #[test]
+    fn should_match_differently_composed_strings_followed_by_the_n_flag() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(r#"{"city":"Zürich"n}"#);
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            r#"{"city":"Zürich"}
+{"city":"Zu\u0308rich"}
+{"city":"Zurich"}
+"#,
+        );
+
+        assert_cmd.assert().success().stdout(
+            r#"{"city":"Zürich"}
+{"city":"Zu\u0308rich"}
+"#,
+        );
+    }
+
+    #[test]
+    fn should_only_match_identically_composed_strings_by_default() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(r#"{"city":"Zürich"}"#);
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            r#"{"city":"Zürich"}
+{"city":"Zu\u0308rich"}
+"#,
+        );
+
+        assert_cmd
+            .assert()
+            .success()
+            .stdout("{\"city\":\"Zürich\"}\n");
+    }
+
+    #[test]
+    fn should_match_compatibility_characters_when_normalizing_to_nfkc() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg("--normalize=nfkc").arg(r#"{"unit"^:"A1"}"#);
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            r#"{"unit":"Ａ１ office"}
+{"unit":"A1 office"}
+{"unit":"B2 office"}
+"#,
+        );
+
+        assert_cmd.assert().success().stdout(
+            r#"{"unit":"Ａ１ office"}
+{"unit":"A1 office"}
+"#,
+        );
+    }
+
+    #[test]
+    fn should_ignore_accents_across_the_whole_pattern() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg("--ignore-accents").arg(r#"{"city":"Zurich"}"#);
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            r#"{"city":"Zürich"}
+{"city":"Zu\u0308rich"}
+{"city":"Zurich"}
+{"city":"Zug"}
+"#,
+        );
+
+        assert_cmd.assert().success().stdout(
+            r#"{"city":"Zürich"}
+{"city":"Zu\u0308rich"}
+{"city":"Zurich"}
+"#,
+        );
+    }
+
+    #[test]
+    fn should_combine_matching_flags() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(r#"[="lodz"ia]"#);
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            r#"["Łódź"]
+["LODZ"]
+["Lublin"]
+"#,
+        );
+
+        assert_cmd.assert().success().stdout(
+            r#"["Łódź"]
+["LODZ"]
+"#,
+        );
+    }
+
+    #[test]
+    fn should_fail_on_normalization_flags_after_a_regular_expression() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(r#"{"city"~/^Zurich$/a}"#);
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "expected a matching flag (one of i), found 'a'",
+        ));
+    }
+}
@@ -68,4 +68,24 @@ mod parameters {
 "#,
         );
     }
+
+    #[test]
+    fn should_leave_empty_objects_written_with_a_space_in_place() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(r#"{"name":"{}","meta":{ }}"#);
+        cmd.arg("--params").arg("jeff");
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            r#"{"name":"jeff","meta":{"id":1}}
+{"name":"{}","meta":{}}
+{"name":"jeff"}
+"#,
+        );
+
+        assert_cmd
+            .assert()
+            .success()
+            .stdout("{\"name\":\"jeff\",\"meta\":{\"id\":1}}\n");
+    }
 }
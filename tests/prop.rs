@@ -178,4 +178,35 @@ mod cli {
 {\"name\":\"John Doe\",\"self\":{\"name\":\"John Doe\",\"job\":{\"title\":\"Unknown-title\"}}}\n",
         );
     }
+
+    #[test]
+    fn should_match_json_porperty_with_an_empty_string_value() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(r#"{"error":""}"#);
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "{\"error\":\"\"}
+{\"error\":\"failed\"}\n",
+        );
+
+        assert_cmd.assert().success().stdout("{\"error\":\"\"}\n");
+    }
+
+    #[test]
+    fn should_match_json_porperty_with_an_escaped_string_value() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(r#"{"query":"say \"hi\""}"#);
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "{\"query\":\"say \\\"hi\\\"\"}
+{\"query\":\"say hi\"}\n",
+        );
+
+        assert_cmd
+            .assert()
+            .success()
+            .stdout("{\"query\":\"say \\\"hi\\\"\"}\n");
+    }
 }
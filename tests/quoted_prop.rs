@@ -0,0 +1,57 @@
+#[cfg(test)]
+mod cli {
+    use std::process::Command;
+
+    use assert_cmd::prelude::*;
+
+    #[test]
+    fn should_match_json_property_with_quoted_name() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(r#".headers."content-type""#);
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "{\"headers\":{\"content-type\":\"application/json\"}}
+{\"headers\":{\"content\":\"application/json\"}}\n",
+        );
+
+        assert_cmd
+            .assert()
+            .success()
+            .stdout("{\"headers\":{\"content-type\":\"application/json\"}}\n");
+    }
+
+    #[test]
+    fn should_match_json_property_with_bracketed_name() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(r#"-^"#).arg(r#".["@timestamp"]"#);
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "{\"@timestamp\":\"2019-03-01T00:00:00Z\",\"message\":\"started\"}
+{\"timestamp\":\"2019-03-01T00:00:00Z\",\"message\":\"started\"}\n",
+        );
+
+        assert_cmd
+            .assert()
+            .success()
+            .stdout("{\"@timestamp\":\"2019-03-01T00:00:00Z\",\"message\":\"started\"}\n");
+    }
+
+    #[test]
+    fn should_match_json_property_with_dotted_name_and_value() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(r#"{"service.name":"auth"}"#);
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "{\"service.name\":\"auth\"}
+{\"service\":{\"name\":\"auth\"}}\n",
+        );
+
+        assert_cmd
+            .assert()
+            .success()
+            .stdout("{\"service.name\":\"auth\"}\n");
+    }
+}
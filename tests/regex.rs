@@ -0,0 +1,51 @@
+#[cfg(test)]
+mod cli {
+    use std::process::Command;
+
+    use assert_cmd::prelude::*;
+
+    #[test]
+    fn should_match_json_property_with_a_value_when_using_the_regex_matcher() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(r#"{"msg"~/timeout after \d+ms/}"#);
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "{\"msg\":\"connection timeout after 250ms\"}
+{\"msg\":\"connection timeout after a while\"}
+{\"msg\":[\"timeout after 250ms\"]}\n",
+        );
+
+        assert_cmd
+            .assert()
+            .success()
+            .stdout("{\"msg\":\"connection timeout after 250ms\"}\n");
+    }
+
+    #[test]
+    fn should_match_array_with_a_string_value_when_using_the_regex_matcher() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(r#".tags[~/^(v\d+\.|release-)/]"#);
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "{\"tags\":[\"latest\",\"v1.2.0\"]}
+{\"tags\":[\"latest\",\"release-candidate\"]}
+{\"tags\":[\"latest\",\"nightly\"]}\n",
+        );
+
+        assert_cmd.assert().success().stdout(
+            "{\"tags\":[\"latest\",\"v1.2.0\"]}
+{\"tags\":[\"latest\",\"release-candidate\"]}\n",
+        );
+    }
+
+    #[test]
+    fn should_fail_when_regex_is_invalid() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(r#"{"msg"~/timeout (/}"#);
+
+        cmd.assert().failure();
+    }
+}
@@ -147,4 +147,87 @@ mod cli {
 
         assert_cmd.assert().failure();
     }
+
+    #[test]
+    fn should_continue_matching_after_sequence() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg("-^").arg(".people[.name].craft");
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "{\"people\":[{\"name\":\"Oleg Kononenko\"},{\"name\":\"Anne McClain\",\"craft\":\"ISS\"}]}
+{\"people\":[{\"name\":\"Oleg Kononenko\"},{\"craft\":\"ISS\"}]}\n",
+        );
+
+        assert_cmd.assert().success().stdout(
+            "{\"people\":[{\"name\":\"Oleg Kononenko\"},{\"name\":\"Anne McClain\",\"craft\":\"ISS\"}]}\n",
+        );
+    }
+
+    #[test]
+    fn should_match_consecutive_sequences() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg("-^").arg(r#"[.][{"id":2}]"#);
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "[[{\"id\":1}],[{\"id\":2}]]
+[[{\"id\":1}],[{\"id\":3}]]\n",
+        );
+
+        assert_cmd
+            .assert()
+            .success()
+            .stdout("[[{\"id\":1}],[{\"id\":2}]]\n");
+    }
+
+    #[test]
+    fn should_match_batch_where_every_job_succeeded() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(r#".jobs[all: {"status":"succeeded"}]"#);
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "{\"jobs\":[{\"status\":\"succeeded\"},{\"status\":\"failed\"}]}\n
+{\"jobs\":[{\"status\":\"succeeded\"},{\"status\":\"succeeded\"}]}\n",
+        );
+
+        assert_cmd
+            .assert()
+            .success()
+            .stdout("{\"jobs\":[{\"status\":\"succeeded\"},{\"status\":\"succeeded\"}]}\n");
+    }
+
+    #[test]
+    fn should_match_batch_where_no_job_failed() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(r#".jobs[none: {"status":"failed"}]"#);
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "{\"jobs\":[{\"status\":\"succeeded\"},{\"status\":\"failed\"}]}\n
+{\"jobs\":[{\"status\":\"succeeded\"},{\"status\":\"queued\"}]}\n",
+        );
+
+        assert_cmd
+            .assert()
+            .success()
+            .stdout("{\"jobs\":[{\"status\":\"succeeded\"},{\"status\":\"queued\"}]}\n");
+    }
+
+    #[test]
+    fn should_match_when_at_least_three_members_match() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(".retries[#>=3: .error]");
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "{\"retries\":[{\"error\":\"timeout\"},{\"error\":\"timeout\"},{\"ok\":true}]}\n
+{\"retries\":[{\"error\":\"timeout\"},{\"error\":\"timeout\"},{\"error\":\"reset\"}]}\n",
+        );
+
+        assert_cmd.assert().success().stdout(
+            "{\"retries\":[{\"error\":\"timeout\"},{\"error\":\"timeout\"},{\"error\":\"reset\"}]}\n",
+        );
+    }
 }
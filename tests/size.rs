@@ -0,0 +1,55 @@
+#[cfg(test)]
+mod cli {
+    use std::process::Command;
+
+    use assert_cmd::prelude::*;
+
+    #[test]
+    fn should_match_empty_array() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(".items#=0");
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "{\"items\":[1]}\n
+{\"items\":[]}\n",
+        );
+
+        assert_cmd.assert().success().stdout("{\"items\":[]}\n");
+    }
+
+    #[test]
+    fn should_match_array_longer_than_count() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(".tags#>2");
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "{\"tags\":[\"a\",\"b\"]}\n
+{\"tags\":[\"a\",\"b\",\"c\"]}\n",
+        );
+
+        assert_cmd
+            .assert()
+            .success()
+            .stdout("{\"tags\":[\"a\",\"b\",\"c\"]}\n");
+    }
+
+    #[test]
+    fn should_match_object_by_number_of_keys() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(".headers#<=1");
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "{\"headers\":{\"host\":\"example.com\",\"accept\":\"*/*\"}}\n
+{\"headers\":{\"host\":\"example.com\"}}\n
+{\"headers\":\"host: example.com\"}\n",
+        );
+
+        assert_cmd
+            .assert()
+            .success()
+            .stdout("{\"headers\":{\"host\":\"example.com\"}}\n");
+    }
+}
@@ -0,0 +1,95 @@
+#[cfg(test)]
+mod cli {
+    use std::process::Command;
+
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+
+    #[test]
+    fn should_match_prop_values_in_the_set() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(r#"{"level" in ["error","fatal","panic"]}"#);
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            r#"{"level":"info"}
+{"level":"fatal"}
+{"level":"error"}
+{"level":["error"]}
+"#,
+        );
+
+        assert_cmd.assert().success().stdout(
+            r#"{"level":"fatal"}
+{"level":"error"}
+"#,
+        );
+    }
+
+    #[test]
+    fn should_match_array_members_in_the_set() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(".responses[in [401,403]]");
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            r#"{"responses":[200,201]}
+{"responses":[200,403.0]}
+{"responses":["401"]}
+"#,
+        );
+
+        assert_cmd
+            .assert()
+            .success()
+            .stdout("{\"responses\":[200,403.0]}\n");
+    }
+
+    #[test]
+    fn should_match_set_membership_deep_inside_a_path() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(r#".request.headers{"method" in ["PUT","DELETE"i]}"#);
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            r#"{"request":{"headers":{"method":"GET"}}}
+{"request":{"headers":{"method":"delete"}}}
+{"request":{"headers":{"method":"put"}}}
+"#,
+        );
+
+        assert_cmd
+            .assert()
+            .success()
+            .stdout("{\"request\":{\"headers\":{\"method\":\"delete\"}}}\n");
+    }
+
+    #[test]
+    fn should_only_fold_the_set_members_which_have_flags() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(r#"{"level" in ["error","FATAL"i]}"#);
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            r#"{"level":"ERROR"}
+{"level":"error"}
+{"level":"Fatal"}
+"#,
+        );
+
+        assert_cmd
+            .assert()
+            .success()
+            .stdout("{\"level\":\"error\"}\n{\"level\":\"Fatal\"}\n");
+    }
+
+    #[test]
+    fn should_only_allow_scalar_values_in_the_set() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(r#"{"tags" in [["a"]]}"#);
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "expected a string, number, boolean or null in the set of values, found \"[\"",
+        ));
+    }
+}
@@ -0,0 +1,73 @@
+#[cfg(test)]
+mod cli {
+    use std::process::Command;
+
+    use assert_cmd::prelude::*;
+
+    #[test]
+    fn should_match_prop_of_the_specified_type() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(".user_id:number");
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "{\"service\":\"auth\",\"user_id\":\"42\"}\n
+{\"service\":\"billing\",\"user_id\":42}\n",
+        );
+
+        assert_cmd
+            .assert()
+            .success()
+            .stdout("{\"service\":\"billing\",\"user_id\":42}\n");
+    }
+
+    #[test]
+    fn should_match_array_members_of_the_specified_type() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(".tags[:null]");
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "{\"tags\":[\"a\",\"b\"]}\n
+{\"tags\":[\"a\",null]}\n",
+        );
+
+        assert_cmd
+            .assert()
+            .success()
+            .stdout("{\"tags\":[\"a\",null]}\n");
+    }
+
+    #[test]
+    fn should_match_object_missing_prop() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg("^.user.email:missing");
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "{\"user\":{\"name\":\"jeff goldblum\",\"email\":\"jeff@example.com\"}}\n
+{\"user\":{\"name\":\"jeff goldblum\"}}\n
+{\"account\":{\"name\":\"jeff goldblum\"}}\n",
+        );
+
+        assert_cmd
+            .assert()
+            .success()
+            .stdout("{\"user\":{\"name\":\"jeff goldblum\"}}\n");
+    }
+
+    #[test]
+    fn shouldnt_match_missing_prop_when_the_prop_is_present() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(".x:missing");
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            "{\"x\":1}\n
+[{\"x\":1}]\n
+{\"y\":1}\n",
+        );
+
+        assert_cmd.assert().success().stdout("{\"y\":1}\n");
+    }
+}
@@ -0,0 +1,81 @@
+#[cfg(test)]
+mod cli {
+    use assert_cmd::prelude::*;
+    use predicates::prelude::*;
+    use std::io::Write;
+    use std::process::Command;
+
+    fn values_file(values: &str) -> tempfile::NamedTempFile {
+        let mut tmp_file = tempfile::NamedTempFile::new().unwrap();
+        tmp_file.write_all(values.as_bytes()).unwrap();
+        tmp_file
+    }
+
+    #[test]
+    fn should_match_prop_values_in_a_referenced_values_file() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        let ids = values_file("acc-001\n\nacc-042\n");
+        cmd.arg(format!(
+            r#"{{"account_id" in @"{}"}}"#,
+            ids.path().to_str().unwrap()
+        ));
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            r#"{"account_id":"acc-001","event":"login"}
+{"account_id":"acc-002","event":"login"}
+{"account_id":"acc-042","event":"logout"}
+"#,
+        );
+
+        assert_cmd.assert().success().stdout(
+            r#"{"account_id":"acc-001","event":"login"}
+{"account_id":"acc-042","event":"logout"}
+"#,
+        );
+    }
+
+    #[test]
+    fn should_read_numeric_looking_ids_as_strings() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        let ids = values_file("12345\n\"00042\"\n");
+        cmd.arg("--values-file")
+            .arg(ids.path())
+            .arg(r#"{"account_id" in @}"#);
+        let mut stdin_cmd = cmd.with_stdin();
+        let mut assert_cmd = stdin_cmd.buffer(
+            r#"{"account_id":"12345"}
+{"account_id":12345}
+{"account_id":"00042"}
+{"account_id":"42"}
+"#,
+        );
+
+        assert_cmd.assert().success().stdout(
+            r#"{"account_id":"12345"}
+{"account_id":"00042"}
+"#,
+        );
+    }
+
+    #[test]
+    fn should_fail_when_the_values_file_cannot_be_read() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(r#"{"account_id" in @missing_ids.txt}"#);
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "expected a readable values file, found \"missing_ids.txt\"",
+        ));
+    }
+
+    #[test]
+    fn should_fail_on_a_bare_reference_without_a_values_file() {
+        let mut cmd = Command::main_binary().unwrap();
+
+        cmd.arg(r#"{"account_id" in @}"#);
+        cmd.assert().failure().stderr(predicate::str::contains(
+            "expected a path after \"@\", or a file given with --values-file",
+        ));
+    }
+}